@@ -1,2 +1,17 @@
+pub mod attract;
 mod event_processor;
-pub use event_processor::GamelogicPlugin;
\ No newline at end of file
+pub mod gesture_bindings;
+pub mod gesture_combos;
+pub mod hand_cursor;
+pub mod hands;
+pub mod info_panel;
+pub mod object_detection;
+pub mod osc_feedback;
+pub mod pinch;
+pub mod remote_commands;
+pub mod selection;
+pub mod state_broadcast;
+
+pub use event_processor::GamelogicPlugin;
+pub use gesture_bindings::InteractionConfig;
+pub use selection::{ProductSelected, Selectable, Selection};
\ No newline at end of file
@@ -0,0 +1,221 @@
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+
+use crate::network::GameEvent;
+use crate::setup::appstate::InteractionMode;
+use crate::setup::config::AppConfig;
+use crate::setup::orbiting_camera::{AutoOrbit, OrbitCamera};
+use crate::setup::particles::ParticleBurst;
+use crate::setup::presets::{ActivePreset, ScenePresets};
+
+/// Ein Halt der Kamera-Tour im Attract-Modus
+#[derive(Deserialize, Clone, Debug)]
+pub struct TourStop {
+    pub target: [f32; 3],
+    pub radius: f32,
+    /// Höhenwinkel in Radiant
+    pub elevation: f32,
+    pub duration_secs: f32,
+}
+
+/// Einstellungen für den Attract-Modus (`interaction.attract`)
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct AttractConfig {
+    pub enabled: bool,
+    /// Nach so vielen Sekunden ohne Hand-Daten startet der Attract-Modus
+    pub idle_secs: f32,
+    /// Orbit-Geschwindigkeit während der Tour (rad/s)
+    pub orbit_speed: f32,
+    /// Wie schnell die Kamera zum nächsten Halt gleitet (1/s)
+    pub camera_smoothing: f32,
+    pub tour: Vec<TourStop>,
+    /// Abstand zwischen zwei Partikel-Bursts (0 = keine Show)
+    pub burst_interval_secs: f32,
+    /// Bursts erscheinen zufällig in diesem Radius um das Kamera-Ziel
+    pub burst_radius: f32,
+}
+
+impl Default for AttractConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            idle_secs: 30.0,
+            orbit_speed: 0.15,
+            camera_smoothing: 0.8,
+            tour: vec![
+                TourStop { target: [0.0, 0.0, 0.0], radius: 12.0, elevation: 0.2, duration_secs: 12.0 },
+                TourStop { target: [0.0, 1.0, 0.0], radius: 6.0, elevation: 0.5, duration_secs: 10.0 },
+                TourStop { target: [0.0, -0.5, 0.0], radius: 18.0, elevation: -0.1, duration_secs: 10.0 },
+            ],
+            burst_interval_secs: 4.0,
+            burst_radius: 3.0,
+        }
+    }
+}
+
+/// Zeitpunkt der letzten Hand-Eingabe
+#[derive(Resource, Default)]
+pub struct IdleWatchdog {
+    pub last_input_at: f32,
+}
+
+/// Fortschritt der Tour und gesicherte Kamera-Parameter für die Rückkehr
+#[derive(Resource)]
+pub struct AttractTour {
+    stop: usize,
+    stop_timer: Timer,
+    burst_timer: Timer,
+    saved_target: Vec3,
+    saved_radius: f32,
+    saved_elevation: f32,
+    saved_orbit_speed: f32,
+    /// Preset beim Start der Tour; wechselt es währenddessen, gilt dessen Kamera
+    saved_preset: Option<String>,
+}
+
+/// Wechselt nach `idle_secs` ohne Hand-Daten in den Attract-Modus und bei neuer Hand sofort zurück
+pub fn detect_idle(
+    mut event_reader: MessageReader<GameEvent>,
+    config: Res<AppConfig>,
+    time: Res<Time>,
+    mode: Res<State<InteractionMode>>,
+    mut next_mode: ResMut<NextState<InteractionMode>>,
+    mut watchdog: ResMut<IdleWatchdog>,
+) {
+    let attract = &config.interaction.attract;
+    let now = time.elapsed_secs();
+
+    let mut hands_arrived = false;
+    for event in event_reader.read() {
+        let is_hand_input = match event {
+            GameEvent::HandCountChanged(count) => *count > 0,
+            GameEvent::HandGesture { .. } | GameEvent::HandPinchDistance { .. } | GameEvent::HandPosition { .. } => true,
+            GameEvent::ObjectDetected { .. } => false,
+        };
+        if is_hand_input {
+            watchdog.last_input_at = now;
+            hands_arrived |= matches!(event, GameEvent::HandCountChanged(count) if *count > 0);
+        }
+    }
+
+    match mode.get() {
+        InteractionMode::Interactive => {
+            if attract.enabled && now - watchdog.last_input_at > attract.idle_secs {
+                info!("💤 {}s ohne Hand-Daten -> Attract-Modus", attract.idle_secs);
+                next_mode.set(InteractionMode::Attract);
+            }
+        }
+        InteractionMode::Attract => {
+            if hands_arrived || now - watchdog.last_input_at < attract.idle_secs || !attract.enabled {
+                info!("👋 Besucher erkannt -> interaktiver Modus");
+                next_mode.set(InteractionMode::Interactive);
+            }
+        }
+    }
+}
+
+/// Beim Start zählt die Idle-Zeit ab dem Wechsel nach `Running`, nicht ab App-Start
+pub fn reset_idle_watchdog(time: Res<Time>, mut watchdog: ResMut<IdleWatchdog>) {
+    watchdog.last_input_at = time.elapsed_secs();
+}
+
+/// Sichert die Kamera und startet die Tour
+pub fn start_attract_tour(
+    mut commands: Commands,
+    config: Res<AppConfig>,
+    active: Res<ActivePreset>,
+    cameras: Query<(&OrbitCamera, &AutoOrbit)>,
+) {
+    let attract = &config.interaction.attract;
+    let Ok((orbit, auto_orbit)) = cameras.single() else {
+        return;
+    };
+
+    commands.insert_resource(AttractTour {
+        stop: 0,
+        stop_timer: Timer::from_seconds(first_stop_duration(attract), TimerMode::Once),
+        burst_timer: Timer::from_seconds(attract.burst_interval_secs.max(0.1), TimerMode::Repeating),
+        saved_target: orbit.target,
+        saved_radius: orbit.radius,
+        saved_elevation: orbit.angle_y,
+        saved_orbit_speed: auto_orbit.speed,
+        saved_preset: active.name.clone(),
+    });
+}
+
+fn first_stop_duration(attract: &AttractConfig) -> f32 {
+    attract.tour.first().map_or(1.0, |stop| stop.duration_secs.max(0.1))
+}
+
+/// Gleitet zwischen den Tour-Halten und löst die Partikel-Show aus
+pub fn run_attract_tour(
+    config: Res<AppConfig>,
+    time: Res<Time>,
+    tour: Option<ResMut<AttractTour>>,
+    mut cameras: Query<(&mut OrbitCamera, &mut AutoOrbit)>,
+    mut burst_writer: MessageWriter<ParticleBurst>,
+) {
+    let Some(mut tour) = tour else {
+        return;
+    };
+    let attract = &config.interaction.attract;
+
+    if !attract.tour.is_empty() && tour.stop_timer.tick(time.delta()).is_finished() {
+        tour.stop = (tour.stop + 1) % attract.tour.len();
+        let duration = attract.tour[tour.stop].duration_secs.max(0.1);
+        tour.stop_timer = Timer::from_seconds(duration, TimerMode::Once);
+    }
+
+    let blend = 1.0 - (-attract.camera_smoothing * time.delta_secs()).exp();
+    for (mut orbit, mut auto_orbit) in cameras.iter_mut() {
+        auto_orbit.speed = attract.orbit_speed;
+        if let Some(stop) = attract.tour.get(tour.stop) {
+            orbit.target = orbit.target.lerp(Vec3::from_array(stop.target), blend);
+            let radius = stop.radius.clamp(orbit.min_radius, orbit.max_radius);
+            orbit.radius += (radius - orbit.radius) * blend;
+            orbit.angle_y += (stop.elevation - orbit.angle_y) * blend;
+        }
+    }
+
+    if attract.burst_interval_secs > 0.0 && tour.burst_timer.tick(time.delta()).just_finished() {
+        let center = cameras.iter().next().map_or(Vec3::ZERO, |(orbit, _)| orbit.target);
+        let mut rng = rand::rng();
+        let offset = Vec3::new(
+            rng.random_range(-1.0..1.0),
+            rng.random_range(-1.0..1.0),
+            rng.random_range(-1.0..1.0),
+        ) * attract.burst_radius;
+        burst_writer.write(ParticleBurst { position: center + offset });
+    }
+}
+
+/// Stellt die Kamera wieder her, wenn Besucher zurück sind.
+/// Hat Playlist oder Fernsteuerung während der Tour das Preset gewechselt, fährt sie
+/// stattdessen die Kamera des neuen Presets an.
+pub fn end_attract_tour(
+    mut commands: Commands,
+    tour: Option<Res<AttractTour>>,
+    active: Res<ActivePreset>,
+    presets: Res<ScenePresets>,
+    mut cameras: Query<(&mut OrbitCamera, &mut AutoOrbit)>,
+) {
+    let Some(tour) = tour else {
+        return;
+    };
+    let (target, radius, orbit_speed) = match active.name.as_deref() {
+        Some(name) if active.name != tour.saved_preset => match presets.get(name) {
+            Some(preset) => (Vec3::from_array(preset.camera.target), preset.camera.radius, preset.camera.orbit_speed),
+            None => (tour.saved_target, tour.saved_radius, tour.saved_orbit_speed),
+        },
+        _ => (tour.saved_target, tour.saved_radius, tour.saved_orbit_speed),
+    };
+    for (mut orbit, mut auto_orbit) in cameras.iter_mut() {
+        orbit.target = target;
+        orbit.radius = radius.clamp(orbit.min_radius, orbit.max_radius);
+        orbit.angle_y = tour.saved_elevation;
+        auto_orbit.speed = orbit_speed;
+    }
+    commands.remove_resource::<AttractTour>();
+}
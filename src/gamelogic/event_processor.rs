@@ -1,6 +1,19 @@
 use bevy::prelude::*;
 use crate::network::GameEvent;
+use crate::setup::appstate::{AppState, InteractionMode};
 use crate::setup::diagnostics::GameEventStats;
+use crate::gamelogic::attract::{detect_idle, end_attract_tour, reset_idle_watchdog, run_attract_tour, start_attract_tour, IdleWatchdog};
+use crate::gamelogic::gesture_bindings::{apply_combo_bindings, apply_gesture_bindings, gesture_orbit_camera, GestureBindingState};
+use crate::gamelogic::gesture_combos::{recognize_gesture_combos, ComboRecognizerState, GestureComboRecognized};
+use crate::gamelogic::hands::{log_hand_transitions, update_hands_state, HandGestureChange, HandPresence, HandsState};
+use crate::gamelogic::hand_cursor::{apply_cursor_hover, draw_hand_cursors, pick_with_hand_cursors, update_hand_cursors, CursorHover};
+use crate::gamelogic::info_panel::{animate_info_panel, spawn_info_panel, update_info_panel_content, InfoPanelState};
+use crate::gamelogic::object_detection::{follow_detected_objects, spawn_detected_objects};
+use crate::gamelogic::osc_feedback::emit_osc_feedback;
+use crate::gamelogic::pinch::{apply_pinch_interaction, PinchState};
+use crate::gamelogic::state_broadcast::{broadcast_app_state, broadcast_health, broadcast_selected_product};
+use crate::gamelogic::remote_commands::apply_remote_commands;
+use crate::gamelogic::selection::{clear_despawned_selection, draw_selection_highlight, ProductSelected, Selection};
 
 fn process_game_events_system(
     mut event_reader: MessageReader<GameEvent>,
@@ -17,20 +30,22 @@ fn process_game_events_system(
                 info!("Gamelogic: Hand {} Geste erkannt: {}", hand_id, gesture);
                 game_event_stats.hand_gesture += 1;
                 game_event_stats.last_event = Some(format!("Gesture: Hand {} - {}", hand_id, gesture));
-                // apply_gesture_to_entity(*hand_id, gesture);
             }
             GameEvent::HandPinchDistance { hand_id, distance } => {
                 // trace! wird oft für kontinuierliche, weniger kritische Updates verwendet
                 trace!("Gamelogic: Hand {} Pinz-Abstand: {}", hand_id, distance);
                 game_event_stats.hand_pinch_distance += 1;
                 game_event_stats.last_event = Some(format!("Pinch: Hand {} - {:.2}", hand_id, distance));
-                // scale_object_by_pinch_distance(*hand_id, *distance);
+            }
+            GameEvent::HandPosition { hand_id, x, y } => {
+                trace!("Gamelogic: Hand {} Position: ({}, {})", hand_id, x, y);
+                game_event_stats.hand_position += 1;
+                game_event_stats.last_event = Some(format!("Position: Hand {} - ({:.2}, {:.2})", hand_id, x, y));
             }
             GameEvent::ObjectDetected { name, x, y } => {
                 info!("Gamelogic: Objekt '{}' an Position ({}, {}) erkannt.", name, x, y);
                 game_event_stats.object_detected += 1;
                 game_event_stats.last_event = Some(format!("Object: {} at ({:.1}, {:.1})", name, x, y));
-                // spawn_entity_at_position(name, *x, *y);
             }
         }
     }
@@ -41,7 +56,68 @@ pub struct GamelogicPlugin;
 
 impl Plugin for GamelogicPlugin {
     fn build(&self, app: &mut App) {
+        app.init_resource::<Selection>()
+            .init_resource::<GestureBindingState>()
+            .init_resource::<PinchState>()
+            .init_resource::<IdleWatchdog>()
+            .init_resource::<HandsState>()
+            .init_resource::<ComboRecognizerState>()
+            .init_resource::<InfoPanelState>()
+            .add_message::<ProductSelected>()
+            .add_message::<CursorHover>()
+            .add_message::<HandGestureChange>()
+            .add_message::<HandPresence>()
+            .add_message::<GestureComboRecognized>();
+
+        // Konsistenter Zustand pro Hand (Leser ordnen sich nach update_hands_state ein)
+        app.add_systems(Update, (
+            update_hands_state,
+            log_hand_transitions,
+            recognize_gesture_combos,
+            apply_combo_bindings,
+        ).chain());
+
         // Füge das Event-Verarbeitungssystem zum Update-Schedule hinzu
-        app.add_systems(Update, process_game_events_system);
+        app.add_systems(Update, (
+            process_game_events_system,
+            apply_gesture_bindings,
+            gesture_orbit_camera,
+            apply_pinch_interaction,
+            (clear_despawned_selection, draw_selection_highlight).chain(),
+        ));
+
+        app.add_systems(Update, (
+            update_hand_cursors,
+            pick_with_hand_cursors,
+            apply_cursor_hover,
+            draw_hand_cursors,
+        ).chain());
+
+        app.add_systems(Update, (
+            spawn_detected_objects,
+            follow_detected_objects,
+        ).chain().run_if(in_state(AppState::Running)));
+
+        // Info-Panel zum ausgewählten Produkt
+        app.add_systems(Startup, spawn_info_panel)
+            .add_systems(Update, (update_info_panel_content, animate_info_panel).chain());
+
+        // Zustand an WebSocket-Clients spiegeln
+        app.add_systems(Update, (
+            broadcast_app_state,
+            broadcast_selected_product,
+            broadcast_health,
+        ));
+
+        app.add_systems(Update, (apply_remote_commands, emit_osc_feedback));
+
+        // Idle-Erkennung und Attract-Modus
+        app.add_systems(OnEnter(AppState::Running), reset_idle_watchdog)
+            .add_systems(OnEnter(InteractionMode::Attract), start_attract_tour)
+            .add_systems(OnExit(InteractionMode::Attract), end_attract_tour)
+            .add_systems(Update, (
+                detect_idle.run_if(in_state(AppState::Running)),
+                run_attract_tour.run_if(in_state(InteractionMode::Attract)),
+            ));
     }
 }
\ No newline at end of file
@@ -0,0 +1,291 @@
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::gamelogic::attract::AttractConfig;
+use crate::gamelogic::gesture_combos::{GestureCombo, GestureComboRecognized};
+use crate::gamelogic::hand_cursor::{HandCursor, HandCursorConfig};
+use crate::gamelogic::hands::HandsConfig;
+use crate::gamelogic::info_panel::InfoPanelConfig;
+use crate::gamelogic::object_detection::DetectionConfig;
+use crate::gamelogic::pinch::PinchConfig;
+use crate::gamelogic::selection::{product_in_focus, ProductSelected, Selectable, Selection};
+use crate::network::GameEvent;
+use crate::setup::asset_registry::AssetRegistry;
+use crate::setup::audio::PlaySound;
+use crate::setup::config::AppConfig;
+use crate::setup::gltf_spawner::{spawn_gltf_with_physics, GltfSpawnConfig};
+use crate::setup::orbiting_camera::OrbitCamera;
+use crate::setup::stresstest::StressTestConfig;
+use crate::setup::world::RadialGravity;
+
+/// Aktion, die eine Geste auslöst (Konfiguration in `interaction.gesture_bindings`)
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum GestureAction {
+    /// Kamera dreht sich, solange die Geste anliegt (rad/s)
+    OrbitCamera { speed: f32 },
+    /// Wählt das Produkt im Fokus aus
+    SelectProduct,
+    /// Hebt das nächste Produkt hervor (ohne Auswahl)
+    HighlightProduct,
+    /// Hebt die Auswahl auf
+    ClearSelection,
+    /// Spawnt ein Modell aus der AssetRegistry am Kamera-Ziel
+    SpawnObject { model: String },
+    ToggleStressTest,
+}
+
+impl GestureAction {
+    /// Kontinuierliche Aktionen laufen bei jedem Event, alle anderen nur beim Gestenwechsel
+    pub fn is_continuous(&self) -> bool {
+        matches!(self, GestureAction::OrbitCamera { .. })
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct InteractionConfig {
+    /// Geste (z.B. "open_palm") -> Aktion
+    pub gesture_bindings: HashMap<String, GestureAction>,
+    /// Gesten-Sequenzen und Zwei-Hand-Combos
+    pub combos: Vec<GestureCombo>,
+    /// Combo-Name -> Aktion (nur diskrete Aktionen)
+    pub combo_bindings: HashMap<String, GestureAction>,
+    /// Mindestabstand zwischen zwei diskreten Aktionen derselben Hand
+    pub action_cooldown_secs: f32,
+    /// Nach dieser Zeit ohne Event endet eine kontinuierliche Aktion
+    pub continuous_timeout_secs: f32,
+    pub pinch: PinchConfig,
+    pub cursor: HandCursorConfig,
+    pub detection: DetectionConfig,
+    pub attract: AttractConfig,
+    pub hands: HandsConfig,
+    pub info_panel: InfoPanelConfig,
+}
+
+impl Default for InteractionConfig {
+    fn default() -> Self {
+        Self {
+            gesture_bindings: HashMap::from([
+                ("open_palm".to_string(), GestureAction::OrbitCamera { speed: 0.6 }),
+                ("fist".to_string(), GestureAction::SelectProduct),
+                ("point".to_string(), GestureAction::HighlightProduct),
+            ]),
+            combos: Vec::new(),
+            combo_bindings: HashMap::new(),
+            action_cooldown_secs: 0.75,
+            continuous_timeout_secs: 0.25,
+            pinch: PinchConfig::default(),
+            cursor: HandCursorConfig::default(),
+            detection: DetectionConfig::default(),
+            attract: AttractConfig::default(),
+            hands: HandsConfig::default(),
+            info_panel: InfoPanelConfig::default(),
+        }
+    }
+}
+
+/// Letzte Geste pro Hand und aktive kontinuierliche Kamerabewegung
+#[derive(Resource, Default)]
+pub struct GestureBindingState {
+    last_gesture: HashMap<i32, String>,
+    last_action_at: HashMap<i32, f32>,
+    orbit_speed: f32,
+    orbit_until: f32,
+}
+
+impl GestureBindingState {
+    /// Startet eine diskrete Aktion, wenn alle beteiligten Hände ihren Cooldown hinter sich haben
+    pub(crate) fn try_start_action(&mut self, hands: &[i32], now: f32, cooldown_secs: f32) -> bool {
+        let cooled_down = hands
+            .iter()
+            .all(|hand| self.last_action_at.get(hand).is_none_or(|last| now - last >= cooldown_secs));
+        if cooled_down {
+            for hand in hands {
+                self.last_action_at.insert(*hand, now);
+            }
+        }
+        cooled_down
+    }
+}
+
+/// Alle Systemparameter, die Gesten-Aktionen verändern dürfen
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct GestureActionContext<'w, 's> {
+    commands: Commands<'w, 's>,
+    selection: ResMut<'w, Selection>,
+    selected_writer: MessageWriter<'w, ProductSelected>,
+    sound_writer: MessageWriter<'w, PlaySound>,
+    stress_test: ResMut<'w, StressTestConfig>,
+    registry: Option<Res<'w, AssetRegistry>>,
+    gltf_assets: Res<'w, Assets<Gltf>>,
+    gltf_mesh_assets: Res<'w, Assets<bevy::gltf::GltfMesh>>,
+    mesh_assets: Res<'w, Assets<Mesh>>,
+    camera_query: Query<'w, 's, (&'static GlobalTransform, &'static OrbitCamera)>,
+    products: Query<'w, 's, (Entity, &'static GlobalTransform), With<Selectable>>,
+    cursors: Query<'w, 's, &'static HandCursor>,
+}
+
+/// Übersetzt `GameEvent::HandGesture` anhand der Bindings aus settings.json in Aktionen
+pub fn apply_gesture_bindings(
+    mut event_reader: MessageReader<GameEvent>,
+    config: Res<AppConfig>,
+    time: Res<Time>,
+    mut state: ResMut<GestureBindingState>,
+    mut context: GestureActionContext,
+) {
+    let interaction = &config.interaction;
+    let now = time.elapsed_secs();
+
+    for event in event_reader.read() {
+        let GameEvent::HandGesture { hand_id, gesture } = event else {
+            continue;
+        };
+
+        let changed = state.last_gesture.get(hand_id) != Some(gesture);
+        state.last_gesture.insert(*hand_id, gesture.clone());
+
+        let Some(action) = interaction.gesture_bindings.get(gesture) else {
+            continue;
+        };
+
+        if action.is_continuous() {
+            if let GestureAction::OrbitCamera { speed } = action {
+                state.orbit_speed = *speed;
+                state.orbit_until = now + interaction.continuous_timeout_secs;
+            }
+            continue;
+        }
+
+        if !changed || !state.try_start_action(&[*hand_id], now, interaction.action_cooldown_secs) {
+            continue;
+        }
+
+        info!("✋ Hand {} Geste '{}' -> {:?}", hand_id, gesture, action);
+        run_discrete_action(*hand_id, action, &mut context);
+    }
+}
+
+/// Führt die in `interaction.combo_bindings` hinterlegte Aktion einer erkannten Combo aus.
+/// Es gilt derselbe Cooldown wie für Gesten, und zwar für jede beteiligte Hand.
+pub fn apply_combo_bindings(
+    mut combo_reader: MessageReader<GestureComboRecognized>,
+    config: Res<AppConfig>,
+    time: Res<Time>,
+    mut state: ResMut<GestureBindingState>,
+    mut context: GestureActionContext,
+) {
+    let cooldown = config.interaction.action_cooldown_secs;
+    let now = time.elapsed_secs();
+
+    for combo in combo_reader.read() {
+        let Some(action) = config.interaction.combo_bindings.get(&combo.name) else {
+            continue;
+        };
+        let Some(hand_id) = combo.hands.first() else {
+            continue;
+        };
+        if !state.try_start_action(&combo.hands, now, cooldown) {
+            continue;
+        }
+
+        info!("🤲 Combo '{}' -> {:?}", combo.name, action);
+        run_discrete_action(*hand_id, action, &mut context);
+    }
+}
+
+fn run_discrete_action(hand_id: i32, action: &GestureAction, context: &mut GestureActionContext) {
+    match action {
+        GestureAction::OrbitCamera { .. } => {}
+        GestureAction::SelectProduct => {
+            // Zeigt die Hand per Cursor auf ein Produkt, hat das Vorrang
+            let pointed = context
+                .cursors
+                .iter()
+                .find(|cursor| cursor.hand_id == hand_id)
+                .and_then(|cursor| cursor.hovered);
+            let focused = context.camera_query.single().ok().and_then(|(camera_transform, _)| {
+                product_in_focus(
+                    camera_transform,
+                    context.products.iter().map(|(e, t)| (e, t.translation())),
+                )
+            });
+            let target = pointed.or(focused).or(context.selection.highlighted);
+            context.selection.select(target, &mut context.selected_writer, &mut context.sound_writer);
+        }
+        GestureAction::HighlightProduct => {
+            let mut products: Vec<Entity> = context.products.iter().map(|(e, _)| e).collect();
+            products.sort();
+            let next = match context.selection.highlighted.and_then(|h| products.iter().position(|e| *e == h)) {
+                Some(index) => products.get((index + 1) % products.len()).copied(),
+                None => products.first().copied(),
+            };
+            context.selection.highlighted = next;
+        }
+        GestureAction::ClearSelection => {
+            context.selection.highlighted = None;
+            context.selection.select(None, &mut context.selected_writer, &mut context.sound_writer);
+        }
+        GestureAction::SpawnObject { model } => spawn_bound_object(model, context),
+        GestureAction::ToggleStressTest => {
+            context.stress_test.enabled = !context.stress_test.enabled;
+            info!("🔥 Stresstest per Geste: {}", if context.stress_test.enabled { "an" } else { "aus" });
+        }
+    }
+}
+
+fn spawn_bound_object(model_key: &str, context: &mut GestureActionContext) {
+    let Some(registry) = context.registry.as_ref() else {
+        return;
+    };
+    let model = match registry.model(model_key) {
+        Ok(model) => model.clone(),
+        Err(e) => {
+            warn!("Geste kann nicht spawnen: {}", e);
+            return;
+        }
+    };
+
+    let target = context
+        .camera_query
+        .single()
+        .map(|(_, orbit)| orbit.target)
+        .unwrap_or(Vec3::ZERO);
+    let mut rng = rand::rng();
+    let offset = Vec3::new(rng.random_range(-1.0..1.0), 3.0, rng.random_range(-1.0..1.0));
+
+    let spawn_config = GltfSpawnConfig::new(model.visual.clone())
+        .with_collider_gltf(model.collider_or_visual())
+        .with_transform(Transform::from_translation(target + offset))
+        .with_mass(1.0)
+        .with_radial_gravity(true);
+
+    if let Some(entity) = spawn_gltf_with_physics(
+        &mut context.commands,
+        &context.gltf_assets,
+        &context.gltf_mesh_assets,
+        &context.mesh_assets,
+        spawn_config,
+        1.0,
+        Some(RadialGravity),
+    ) {
+        context.commands.entity(entity).insert(Selectable);
+        info!("✨ '{}' per Geste gespawnt", model_key);
+    }
+}
+
+/// Dreht die Kamera, solange eine Orbit-Geste anliegt
+pub fn gesture_orbit_camera(
+    state: Res<GestureBindingState>,
+    time: Res<Time>,
+    mut query: Query<&mut OrbitCamera>,
+) {
+    if time.elapsed_secs() > state.orbit_until {
+        return;
+    }
+    for mut orbit in query.iter_mut() {
+        orbit.angle_x += state.orbit_speed * time.delta_secs();
+    }
+}
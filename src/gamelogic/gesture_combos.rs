@@ -0,0 +1,356 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::gamelogic::hands::{HandGestureChange, HandsState};
+use crate::setup::config::AppConfig;
+
+/// Wie viele Gesten pro Hand für Sequenzen gemerkt werden
+const HISTORY_LEN: usize = 8;
+
+/// Eine Gesten-Kombination (`interaction.combos`)
+///
+/// Beispiele:
+/// - `{ "name": "test_drop", "sequence": ["point", "open_palm"], "within_secs": 0.8 }`
+/// - `{ "name": "confirm", "sequence": ["thumbs_up"], "hold_secs": 1.0 }`
+/// - `{ "name": "frame", "both_hands": ["point", "point"], "within_secs": 0.3 }`
+#[derive(Deserialize, Clone, Debug)]
+pub struct GestureCombo {
+    pub name: String,
+    /// Gesten nacheinander auf derselben Hand
+    #[serde(default)]
+    pub sequence: Vec<String>,
+    /// Gesten zweier Hände, die gleichzeitig anliegen müssen
+    #[serde(default)]
+    pub both_hands: Option<[String; 2]>,
+    /// Zeitfenster zwischen erster und letzter Geste (bzw. zwischen beiden Händen)
+    #[serde(default = "default_within_secs")]
+    pub within_secs: f32,
+    /// Die letzte Geste muss so lange gehalten werden (0 = sofort auslösen)
+    #[serde(default)]
+    pub hold_secs: f32,
+}
+
+fn default_within_secs() -> f32 {
+    0.8
+}
+
+/// Prüft die Combo-Definitionen beim Laden der Konfiguration
+pub fn validate_combos(combos: &[GestureCombo]) -> Vec<String> {
+    let mut problems = Vec::new();
+    for combo in combos {
+        if combo.sequence.is_empty() == combo.both_hands.is_none() {
+            problems.push(format!(
+                "interaction.combos '{}': genau eines von sequence oder both_hands angeben",
+                combo.name
+            ));
+        }
+        if combo.sequence.len() > HISTORY_LEN {
+            problems.push(format!(
+                "interaction.combos '{}': höchstens {} Gesten pro Sequenz",
+                combo.name, HISTORY_LEN
+            ));
+        }
+        if combo.within_secs <= 0.0 || combo.hold_secs < 0.0 {
+            problems.push(format!(
+                "interaction.combos '{}': within_secs muss > 0 und hold_secs >= 0 sein",
+                combo.name
+            ));
+        }
+    }
+    problems
+}
+
+/// Eine erkannte Kombination, bindbar über `interaction.combo_bindings`
+#[derive(Message, Debug, Clone)]
+pub struct GestureComboRecognized {
+    pub name: String,
+    /// Beteiligte Hände (bei Sequenzen genau eine)
+    pub hands: Vec<i32>,
+}
+
+/// Erfüllte Combo, deren letzte Geste noch gehalten werden muss
+struct PendingHold {
+    combo: usize,
+    /// Hand und Geste, die bis zum Ablauf anliegen müssen
+    hands: Vec<(i32, String)>,
+    since: f32,
+}
+
+/// Gestenverlauf pro Hand und Combos, die auf ihre Haltezeit warten
+#[derive(Resource, Default)]
+pub struct ComboRecognizerState {
+    history: HashMap<i32, Vec<(String, f32)>>,
+    pending: Vec<PendingHold>,
+}
+
+impl ComboRecognizerState {
+    /// Verarbeitet die Gestenwechsel eines Frames und liefert die erkannten Combos
+    pub fn update<'a>(
+        &mut self,
+        combos: &[GestureCombo],
+        changes: impl IntoIterator<Item = &'a HandGestureChange>,
+        hands: &HandsState,
+        now: f32,
+    ) -> Vec<GestureComboRecognized> {
+        for change in changes {
+            let HandGestureChange::Entered { hand_id, gesture } = change else {
+                continue;
+            };
+            let history = self.history.entry(*hand_id).or_default();
+            history.push((gesture.clone(), now));
+            if history.len() > HISTORY_LEN {
+                history.remove(0);
+            }
+
+            for (index, combo) in combos.iter().enumerate() {
+                let Some(participants) = match_combo(combo, *hand_id, gesture, &self.history, hands, now) else {
+                    continue;
+                };
+                // Jede Combo wartet höchstens einmal gleichzeitig
+                if self.pending.iter().any(|pending| pending.combo == index) {
+                    continue;
+                }
+                self.pending.push(PendingHold { combo: index, hands: participants, since: now });
+            }
+        }
+
+        // Haltezeiten prüfen; lässt eine Hand los, verfällt die Combo
+        let mut recognized = Vec::new();
+        self.pending.retain(|pending| {
+            let still_held = pending.hands.iter().all(|(hand_id, gesture)| {
+                hands.get(*hand_id).is_some_and(|hand| hand.gesture.as_ref() == Some(gesture))
+            });
+            let Some(combo) = combos.get(pending.combo) else {
+                return false;
+            };
+            if !still_held {
+                return false;
+            }
+            if now - pending.since >= combo.hold_secs {
+                recognized.push(GestureComboRecognized {
+                    name: combo.name.clone(),
+                    hands: pending.hands.iter().map(|(hand_id, _)| *hand_id).collect(),
+                });
+                return false;
+            }
+            true
+        });
+
+        // Verbrauchte Gesten lösen dieselbe Sequenz nicht erneut aus
+        for combo in &recognized {
+            for hand_id in &combo.hands {
+                self.history.remove(hand_id);
+            }
+        }
+
+        // Verlauf verschwundener Hände verwerfen
+        self.history.retain(|hand_id, _| hands.get(*hand_id).is_some());
+        recognized
+    }
+}
+
+/// Erkennt Sequenzen, Zwei-Hand-Combos und Haltezeiten auf Basis der Gestenwechsel
+pub fn recognize_gesture_combos(
+    mut change_reader: MessageReader<HandGestureChange>,
+    config: Res<AppConfig>,
+    time: Res<Time>,
+    hands: Res<HandsState>,
+    mut state: ResMut<ComboRecognizerState>,
+    mut combo_writer: MessageWriter<GestureComboRecognized>,
+) {
+    let recognized = state.update(&config.interaction.combos, change_reader.read(), &hands, time.elapsed_secs());
+    for combo in recognized {
+        info!("🤲 Combo '{}' erkannt (Hände {:?})", combo.name, combo.hands);
+        combo_writer.write(combo);
+    }
+}
+
+/// Liefert die beteiligten Hände samt Geste, wenn `combo` mit der neuen Geste erfüllt ist
+fn match_combo(
+    combo: &GestureCombo,
+    hand_id: i32,
+    gesture: &str,
+    history: &HashMap<i32, Vec<(String, f32)>>,
+    hands: &HandsState,
+    now: f32,
+) -> Option<Vec<(i32, String)>> {
+    if let Some([first, second]) = &combo.both_hands {
+        let partner_gesture = if gesture == first {
+            second
+        } else if gesture == second {
+            first
+        } else {
+            return None;
+        };
+        let partner = hands.iter().find(|other| {
+            other.hand_id != hand_id
+                && other.gesture.as_ref() == Some(partner_gesture)
+                && now - other.gesture_since <= combo.within_secs
+        })?;
+        return Some(vec![(hand_id, gesture.to_string()), (partner.hand_id, partner_gesture.clone())]);
+    }
+
+    let recent = history.get(&hand_id)?;
+    if combo.sequence.is_empty() || recent.len() < combo.sequence.len() {
+        return None;
+    }
+    let tail = &recent[recent.len() - combo.sequence.len()..];
+    let matches = tail.iter().zip(&combo.sequence).all(|((seen, _), expected)| seen == expected);
+    let started_at = tail[0].1;
+    (matches && now - started_at <= combo.within_secs).then(|| vec![(hand_id, gesture.to_string())])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gamelogic::gesture_bindings::GestureBindingState;
+
+    fn sequence(name: &str, gestures: &[&str], within_secs: f32) -> GestureCombo {
+        GestureCombo {
+            name: name.to_string(),
+            sequence: gestures.iter().map(|g| g.to_string()).collect(),
+            both_hands: None,
+            within_secs,
+            hold_secs: 0.0,
+        }
+    }
+
+    fn both_hands(name: &str, first: &str, second: &str, within_secs: f32, hold_secs: f32) -> GestureCombo {
+        GestureCombo {
+            name: name.to_string(),
+            sequence: Vec::new(),
+            both_hands: Some([first.to_string(), second.to_string()]),
+            within_secs,
+            hold_secs,
+        }
+    }
+
+    /// Hände und Erkenner ohne App; Gesten werden direkt gesetzt
+    struct Recognizer {
+        combos: Vec<GestureCombo>,
+        hands: HandsState,
+        state: ComboRecognizerState,
+    }
+
+    impl Recognizer {
+        fn new(combos: Vec<GestureCombo>) -> Self {
+            Self { combos, hands: HandsState::default(), state: ComboRecognizerState::default() }
+        }
+
+        fn gesture(&mut self, hand_id: i32, gesture: &str, now: f32) -> Vec<String> {
+            self.hands.set_gesture(hand_id, Some(gesture), now);
+            let change = HandGestureChange::Entered { hand_id, gesture: gesture.to_string() };
+            names(self.state.update(&self.combos, [&change], &self.hands, now))
+        }
+
+        fn release(&mut self, hand_id: i32, now: f32) -> Vec<String> {
+            self.hands.set_gesture(hand_id, None, now);
+            self.tick(now)
+        }
+
+        fn tick(&mut self, now: f32) -> Vec<String> {
+            names(self.state.update(&self.combos, [], &self.hands, now))
+        }
+    }
+
+    fn names(recognized: Vec<GestureComboRecognized>) -> Vec<String> {
+        recognized.into_iter().map(|combo| combo.name).collect()
+    }
+
+    #[test]
+    fn sequence_within_window_is_recognized() {
+        let mut recognizer = Recognizer::new(vec![sequence("drop", &["point", "open_palm"], 0.8)]);
+        assert!(recognizer.gesture(1, "point", 0.0).is_empty());
+        assert_eq!(recognizer.gesture(1, "open_palm", 0.8), vec!["drop"]);
+    }
+
+    #[test]
+    fn sequence_outside_window_is_ignored() {
+        let mut recognizer = Recognizer::new(vec![sequence("drop", &["point", "open_palm"], 0.8)]);
+        recognizer.gesture(1, "point", 0.0);
+        assert!(recognizer.gesture(1, "open_palm", 0.9).is_empty());
+    }
+
+    #[test]
+    fn sequence_needs_the_same_hand_and_order() {
+        let mut recognizer = Recognizer::new(vec![sequence("drop", &["point", "open_palm"], 0.8)]);
+        recognizer.gesture(1, "open_palm", 0.0);
+        assert!(recognizer.gesture(1, "point", 0.1).is_empty());
+        assert!(recognizer.gesture(2, "open_palm", 0.2).is_empty());
+    }
+
+    #[test]
+    fn recognized_sequence_is_consumed() {
+        let mut recognizer = Recognizer::new(vec![sequence("drop", &["point", "open_palm"], 0.8)]);
+        recognizer.gesture(1, "point", 0.0);
+        assert_eq!(recognizer.gesture(1, "open_palm", 0.2), vec!["drop"]);
+        recognizer.gesture(1, "fist", 0.3);
+        assert!(recognizer.gesture(1, "open_palm", 0.4).is_empty());
+        recognizer.gesture(1, "point", 0.5);
+        assert_eq!(recognizer.gesture(1, "open_palm", 0.6), vec!["drop"]);
+    }
+
+    #[test]
+    fn both_hands_within_window_are_recognized() {
+        let mut recognizer = Recognizer::new(vec![both_hands("frame", "point", "fist", 0.3, 0.0)]);
+        assert!(recognizer.gesture(1, "fist", 0.0).is_empty());
+        recognizer.hands.set_gesture(2, Some("point"), 0.2);
+        let change = HandGestureChange::Entered { hand_id: 2, gesture: "point".to_string() };
+        let recognized = recognizer.state.update(&recognizer.combos, [&change], &recognizer.hands, 0.2);
+        assert_eq!(recognized.len(), 1);
+        assert_eq!(recognized[0].hands, vec![2, 1]);
+    }
+
+    #[test]
+    fn both_hands_too_far_apart_are_ignored() {
+        let mut recognizer = Recognizer::new(vec![both_hands("frame", "point", "point", 0.3, 0.0)]);
+        recognizer.gesture(1, "point", 0.0);
+        assert!(recognizer.gesture(2, "point", 0.5).is_empty());
+    }
+
+    #[test]
+    fn hold_fires_after_hold_secs() {
+        let mut recognizer = Recognizer::new(vec![both_hands("reset", "open_palm", "open_palm", 0.5, 1.0)]);
+        recognizer.gesture(1, "open_palm", 0.0);
+        assert!(recognizer.gesture(2, "open_palm", 0.2).is_empty());
+        assert!(recognizer.tick(1.1).is_empty());
+        assert_eq!(recognizer.tick(1.3), vec!["reset"]);
+        assert!(recognizer.tick(2.5).is_empty());
+    }
+
+    #[test]
+    fn hold_is_cancelled_when_a_hand_lets_go() {
+        let mut recognizer = Recognizer::new(vec![both_hands("reset", "open_palm", "open_palm", 0.5, 1.0)]);
+        recognizer.gesture(1, "open_palm", 0.0);
+        recognizer.gesture(2, "open_palm", 0.2);
+        assert!(recognizer.release(1, 0.6).is_empty());
+        assert!(recognizer.tick(1.5).is_empty());
+    }
+
+    #[test]
+    fn combo_actions_respect_the_cooldown_of_every_hand() {
+        let mut state = GestureBindingState::default();
+        assert!(state.try_start_action(&[1], 0.0, 0.75));
+        assert!(!state.try_start_action(&[1, 2], 0.5, 0.75));
+        assert!(state.try_start_action(&[2], 0.5, 0.75));
+        assert!(state.try_start_action(&[1, 2], 1.25, 0.75));
+        assert!(!state.try_start_action(&[2], 1.5, 0.75));
+    }
+
+    #[test]
+    fn invalid_combos_are_reported() {
+        let mut mixed = sequence("mixed", &["point"], 0.5);
+        mixed.both_hands = Some(["fist".to_string(), "fist".to_string()]);
+        let combos = [
+            sequence("ok", &["point", "fist"], 0.5),
+            sequence("empty", &[], 0.5),
+            mixed,
+            sequence("too_long", &["point"; HISTORY_LEN + 1], 0.5),
+            sequence("no_window", &["point"], 0.0),
+        ];
+        let problems = validate_combos(&combos);
+        assert_eq!(problems.len(), 4);
+        assert!(!problems.iter().any(|problem| problem.contains("'ok'")));
+    }
+}
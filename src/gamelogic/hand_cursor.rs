@@ -0,0 +1,205 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::gamelogic::selection::Selection;
+use crate::network::GameEvent;
+use crate::setup::config::AppConfig;
+use crate::setup::orbiting_camera::OrbitCamera;
+
+/// Einstellungen für die Hand-Cursor (`interaction.cursor`)
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct HandCursorConfig {
+    pub enabled: bool,
+    /// Spiegelt die x-Achse (Kamera schaut durch die Scheibe auf die Besucher)
+    pub mirror_x: bool,
+    /// Maximale Länge des Picking-Strahls
+    pub max_distance: f32,
+    /// Abstand des Cursors zur Kamera, wenn der Strahl nichts trifft
+    pub idle_distance: f32,
+    /// Nach dieser Zeit ohne Positions-Event verschwindet der Cursor
+    pub timeout_secs: f32,
+    pub radius: f32,
+}
+
+impl Default for HandCursorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            mirror_x: false,
+            max_distance: 100.0,
+            idle_distance: 8.0,
+            timeout_secs: 1.0,
+            radius: 0.15,
+        }
+    }
+}
+
+/// 3D-Cursor einer getrackten Hand
+#[derive(Component, Debug)]
+pub struct HandCursor {
+    pub hand_id: i32,
+    /// Normalisierte Bildschirmposition (0..1, Ursprung oben links)
+    pub screen_position: Vec2,
+    /// Produkt (RigidBody), auf das der Cursor gerade zeigt
+    pub hovered: Option<Entity>,
+    last_seen: f32,
+}
+
+/// Hover-Wechsel eines Hand-Cursors über einem Produkt
+#[derive(Message, Clone, Debug)]
+pub enum CursorHover {
+    Entered { hand_id: i32, entity: Entity },
+    Left { hand_id: i32, entity: Entity },
+}
+
+/// Legt Cursor pro Hand an, aktualisiert ihre Bildschirmposition und entfernt verwaiste Cursor
+pub fn update_hand_cursors(
+    mut commands: Commands,
+    mut event_reader: MessageReader<GameEvent>,
+    mut hover_writer: MessageWriter<CursorHover>,
+    config: Res<AppConfig>,
+    time: Res<Time>,
+    mut cursors: Query<(Entity, &mut HandCursor)>,
+) {
+    let cursor_config = &config.interaction.cursor;
+    let now = time.elapsed_secs();
+    let mut all_hands_gone = false;
+    // Erst nach der Schleife spawnen, damit mehrere Positionen einer neuen Hand nur einen Cursor ergeben
+    let mut new_cursors: HashMap<i32, HandCursor> = HashMap::new();
+
+    for event in event_reader.read() {
+        match event {
+            GameEvent::HandPosition { hand_id, x, y } if cursor_config.enabled => {
+                let x = if cursor_config.mirror_x { 1.0 - x } else { *x };
+                let screen_position = Vec2::new(x, *y).clamp(Vec2::ZERO, Vec2::ONE);
+
+                match cursors.iter_mut().find(|(_, cursor)| cursor.hand_id == *hand_id) {
+                    Some((_, mut cursor)) => {
+                        cursor.screen_position = screen_position;
+                        cursor.last_seen = now;
+                    }
+                    None => {
+                        new_cursors.insert(
+                            *hand_id,
+                            HandCursor { hand_id: *hand_id, screen_position, hovered: None, last_seen: now },
+                        );
+                    }
+                }
+            }
+            GameEvent::HandCountChanged(0) => {
+                all_hands_gone = true;
+                new_cursors.clear();
+            }
+            _ => {}
+        }
+    }
+
+    for (hand_id, cursor) in new_cursors {
+        commands.spawn((Name::new(format!("HandCursor {}", hand_id)), cursor, Transform::default()));
+        info!("👆 Cursor für Hand {} angelegt", hand_id);
+    }
+
+    for (entity, cursor) in cursors.iter() {
+        if all_hands_gone || now - cursor.last_seen > cursor_config.timeout_secs {
+            if let Some(hovered) = cursor.hovered {
+                hover_writer.write(CursorHover::Left { hand_id: cursor.hand_id, entity: hovered });
+            }
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Schießt pro Cursor einen Strahl durch die Kamera in die Physik-Welt und aktualisiert den Hover
+pub fn pick_with_hand_cursors(
+    spatial_query: SpatialQuery,
+    config: Res<AppConfig>,
+    mut hover_writer: MessageWriter<CursorHover>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<OrbitCamera>>,
+    mut cursors: Query<(&mut HandCursor, &mut Transform)>,
+    collider_of: Query<&ColliderOf>,
+    bodies: Query<(), With<RigidBody>>,
+) {
+    let Ok((camera, camera_transform)) = camera_query.single() else {
+        return;
+    };
+    let Some(viewport_size) = camera.logical_viewport_size() else {
+        return;
+    };
+    let cursor_config = &config.interaction.cursor;
+
+    for (mut cursor, mut transform) in cursors.iter_mut() {
+        let viewport_position = cursor.screen_position * viewport_size;
+        let Ok(ray) = camera.viewport_to_world(camera_transform, viewport_position) else {
+            continue;
+        };
+
+        let hit = spatial_query.cast_ray(
+            ray.origin,
+            ray.direction,
+            cursor_config.max_distance,
+            true,
+            &SpatialQueryFilter::default(),
+        );
+
+        // Collider gehören ggf. zu einem Kind-Entity -> auf den RigidBody abbilden
+        let hovered = hit.and_then(|hit| {
+            let body = collider_of.get(hit.entity).map_or(hit.entity, |c| c.body);
+            bodies.contains(body).then_some(body)
+        });
+        let distance = hit.map_or(cursor_config.idle_distance, |hit| hit.distance);
+        transform.translation = ray.get_point(distance);
+
+        if hovered != cursor.hovered {
+            if let Some(previous) = cursor.hovered {
+                hover_writer.write(CursorHover::Left { hand_id: cursor.hand_id, entity: previous });
+            }
+            if let Some(entity) = hovered {
+                hover_writer.write(CursorHover::Entered { hand_id: cursor.hand_id, entity });
+            }
+            cursor.hovered = hovered;
+        }
+    }
+}
+
+/// Überträgt Hover-Wechsel auf die Hervorhebung der Auswahl
+pub fn apply_cursor_hover(
+    mut hover_reader: MessageReader<CursorHover>,
+    mut selection: ResMut<Selection>,
+    cursors: Query<&HandCursor>,
+) {
+    for hover in hover_reader.read() {
+        match hover {
+            CursorHover::Entered { hand_id, entity } => {
+                debug!("👆 Hand {} zeigt auf {:?}", hand_id, entity);
+                selection.highlighted = Some(*entity);
+            }
+            CursorHover::Left { hand_id, entity } => {
+                debug!("👆 Hand {} verlässt {:?}", hand_id, entity);
+                let still_hovered = cursors.iter().any(|cursor| cursor.hovered == Some(*entity));
+                if selection.highlighted == Some(*entity) && !still_hovered {
+                    selection.highlighted = None;
+                }
+            }
+        }
+    }
+}
+
+/// Zeichnet die Cursor als Gizmo-Kugeln (beim Hover größer und gelb)
+pub fn draw_hand_cursors(
+    mut gizmos: Gizmos,
+    config: Res<AppConfig>,
+    cursors: Query<(&HandCursor, &Transform)>,
+) {
+    let radius = config.interaction.cursor.radius;
+    for (cursor, transform) in cursors.iter() {
+        let (color, scale) = if cursor.hovered.is_some() {
+            (Color::srgb(1.0, 0.8, 0.1), 1.5)
+        } else {
+            (Color::srgb(0.9, 0.9, 0.9), 1.0)
+        };
+        gizmos.sphere(Isometry3d::from_translation(transform.translation), radius * scale, color);
+    }
+}
@@ -0,0 +1,237 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::network::GameEvent;
+use crate::setup::config::AppConfig;
+
+/// Einstellungen für die Hand-Verfolgung (`interaction.hands`)
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct HandsConfig {
+    /// Hände ohne Event seit dieser Zeit gelten als verschwunden
+    pub stale_timeout_secs: f32,
+    /// Gesten-Strings, die "keine Geste" bedeuten
+    pub neutral_gestures: Vec<String>,
+    /// Glättung der Pinch-Geschwindigkeit pro Event (1 = ungefiltert)
+    pub velocity_smoothing: f32,
+}
+
+impl Default for HandsConfig {
+    fn default() -> Self {
+        Self {
+            stale_timeout_secs: 1.0,
+            neutral_gestures: vec!["none".to_string(), "unknown".to_string()],
+            velocity_smoothing: 0.5,
+        }
+    }
+}
+
+/// Zusammengeführter Zustand einer Hand
+#[derive(Debug, Clone)]
+pub struct TrackedHand {
+    pub hand_id: i32,
+    pub gesture: Option<String>,
+    /// Seit wann die aktuelle Geste anliegt
+    pub gesture_since: f32,
+    pub pinch_distance: Option<f32>,
+    /// Änderung des Pinch-Abstands pro Sekunde (positiv = Finger öffnen sich)
+    pub pinch_velocity: f32,
+    pinch_seen_at: f32,
+    /// Normalisierte Bildposition (0..1, Ursprung oben links)
+    pub position: Option<Vec2>,
+    pub last_seen: f32,
+}
+
+impl TrackedHand {
+    fn new(hand_id: i32, now: f32) -> Self {
+        Self {
+            hand_id,
+            gesture: None,
+            gesture_since: now,
+            pinch_distance: None,
+            pinch_velocity: 0.0,
+            pinch_seen_at: now,
+            position: None,
+            last_seen: now,
+        }
+    }
+
+    /// Wie lange die aktuelle Geste schon gehalten wird
+    pub fn gesture_held_secs(&self, now: f32) -> f32 {
+        if self.gesture.is_some() { now - self.gesture_since } else { 0.0 }
+    }
+}
+
+/// Alle aktuell sichtbaren Hände, nach `hand_id`
+#[derive(Resource, Default)]
+pub struct HandsState {
+    hands: HashMap<i32, TrackedHand>,
+    /// Zuletzt vom Tracker gemeldete Handanzahl
+    pub reported_count: i32,
+}
+
+impl HandsState {
+    pub fn get(&self, hand_id: i32) -> Option<&TrackedHand> {
+        self.hands.get(&hand_id)
+    }
+
+    /// Alle Hände, nach `hand_id` sortiert
+    pub fn iter(&self) -> impl Iterator<Item = &TrackedHand> {
+        let mut hands: Vec<&TrackedHand> = self.hands.values().collect();
+        hands.sort_by_key(|hand| hand.hand_id);
+        hands.into_iter()
+    }
+}
+
+#[cfg(test)]
+impl HandsState {
+    /// Setzt die Geste einer Hand direkt, ohne `GameEvent`s
+    pub fn set_gesture(&mut self, hand_id: i32, gesture: Option<&str>, now: f32) {
+        let hand = self.hands.entry(hand_id).or_insert_with(|| TrackedHand::new(hand_id, now));
+        hand.gesture = gesture.map(str::to_string);
+        hand.gesture_since = now;
+        hand.last_seen = now;
+    }
+}
+
+/// Gestenwechsel einer Hand
+#[derive(Message, Debug, Clone)]
+pub enum HandGestureChange {
+    Entered { hand_id: i32, gesture: String },
+    Left { hand_id: i32, gesture: String, held_secs: f32 },
+}
+
+/// Eine Hand taucht auf oder ist verschwunden (Timeout bzw. Handanzahl 0)
+#[derive(Message, Debug, Clone, Copy)]
+pub enum HandPresence {
+    Appeared { hand_id: i32 },
+    Lost { hand_id: i32 },
+}
+
+/// Führt alle `GameEvent`s zu einem konsistenten Zustand pro Hand zusammen
+pub fn update_hands_state(
+    mut event_reader: MessageReader<GameEvent>,
+    config: Res<AppConfig>,
+    time: Res<Time>,
+    mut state: ResMut<HandsState>,
+    mut gesture_writer: MessageWriter<HandGestureChange>,
+    mut presence_writer: MessageWriter<HandPresence>,
+) {
+    let hands_config = &config.interaction.hands;
+    let now = time.elapsed_secs();
+
+    for event in event_reader.read() {
+        let hand_id = match event {
+            GameEvent::HandCountChanged(count) => {
+                state.reported_count = *count;
+                if *count <= 0 {
+                    let ids: Vec<i32> = state.hands.keys().copied().collect();
+                    for hand_id in ids {
+                        remove_hand(&mut state, hand_id, now, &mut gesture_writer, &mut presence_writer);
+                    }
+                }
+                continue;
+            }
+            // Objekte gehören zu keiner Hand
+            GameEvent::ObjectDetected { .. } => continue,
+            GameEvent::HandGesture { hand_id, .. }
+            | GameEvent::HandPinchDistance { hand_id, .. }
+            | GameEvent::HandPosition { hand_id, .. } => *hand_id,
+        };
+
+        let hand = state.hands.entry(hand_id).or_insert_with(|| {
+            presence_writer.write(HandPresence::Appeared { hand_id });
+            TrackedHand::new(hand_id, now)
+        });
+        hand.last_seen = now;
+
+        match event {
+            GameEvent::HandGesture { gesture, .. } => {
+                let gesture = (!gesture.is_empty() && !hands_config.neutral_gestures.contains(gesture))
+                    .then(|| gesture.clone());
+                if hand.gesture == gesture {
+                    continue;
+                }
+                if let Some(previous) = hand.gesture.take() {
+                    gesture_writer.write(HandGestureChange::Left {
+                        hand_id,
+                        gesture: previous,
+                        held_secs: now - hand.gesture_since,
+                    });
+                }
+                if let Some(gesture) = &gesture {
+                    gesture_writer.write(HandGestureChange::Entered { hand_id, gesture: gesture.clone() });
+                }
+                hand.gesture = gesture;
+                hand.gesture_since = now;
+            }
+            GameEvent::HandPinchDistance { distance, .. } => {
+                if !distance.is_finite() {
+                    continue;
+                }
+                let dt = now - hand.pinch_seen_at;
+                if let Some(previous) = hand.pinch_distance {
+                    // Mehrere Events im selben Frame liefern keine Zeitbasis
+                    if dt > 0.0 {
+                        let velocity = (distance - previous) / dt;
+                        let smoothing = hands_config.velocity_smoothing.clamp(0.0, 1.0);
+                        hand.pinch_velocity += (velocity - hand.pinch_velocity) * smoothing;
+                    }
+                }
+                hand.pinch_distance = Some(*distance);
+                hand.pinch_seen_at = now;
+            }
+            GameEvent::HandPosition { x, y, .. } => {
+                hand.position = Some(Vec2::new(*x, *y));
+            }
+            GameEvent::HandCountChanged(_) | GameEvent::ObjectDetected { .. } => {}
+        }
+    }
+
+    let stale: Vec<i32> = state
+        .hands
+        .values()
+        .filter(|hand| now - hand.last_seen > hands_config.stale_timeout_secs)
+        .map(|hand| hand.hand_id)
+        .collect();
+    for hand_id in stale {
+        remove_hand(&mut state, hand_id, now, &mut gesture_writer, &mut presence_writer);
+    }
+}
+
+fn remove_hand(
+    state: &mut HandsState,
+    hand_id: i32,
+    now: f32,
+    gesture_writer: &mut MessageWriter<HandGestureChange>,
+    presence_writer: &mut MessageWriter<HandPresence>,
+) {
+    let Some(hand) = state.hands.remove(&hand_id) else {
+        return;
+    };
+    if let Some(gesture) = hand.gesture {
+        gesture_writer.write(HandGestureChange::Left { hand_id, gesture, held_secs: now - hand.gesture_since });
+    }
+    presence_writer.write(HandPresence::Lost { hand_id });
+}
+
+pub fn log_hand_transitions(
+    mut gesture_reader: MessageReader<HandGestureChange>,
+    mut presence_reader: MessageReader<HandPresence>,
+) {
+    for presence in presence_reader.read() {
+        match presence {
+            HandPresence::Appeared { hand_id } => info!("🖐️ Hand {} aufgetaucht", hand_id),
+            HandPresence::Lost { hand_id } => info!("🖐️ Hand {} verschwunden", hand_id),
+        }
+    }
+    for change in gesture_reader.read() {
+        match change {
+            HandGestureChange::Entered { hand_id, gesture } => debug!("Hand {} beginnt '{}'", hand_id, gesture),
+            HandGestureChange::Left { hand_id, gesture, held_secs } => {
+                debug!("Hand {} beendet '{}' nach {:.2}s", hand_id, gesture, held_secs)
+            }
+        }
+    }
+}
@@ -0,0 +1,312 @@
+use bevy::asset::RenderAssetUsages;
+use bevy::image::ImageSampler;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use qrcode::QrCode;
+use serde::Deserialize;
+
+use crate::gamelogic::selection::ProductSelected;
+use crate::setup::catalog::{Product, ProductCatalog};
+use crate::setup::config::{srgb, AppConfig};
+use crate::setup::orbiting_camera::OrbitCamera;
+
+/// Woran sich das Info-Panel ausrichtet
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PanelAnchor {
+    /// Feste Bildschirmecke (`corner`)
+    Screen,
+    /// Neben der projizierten Position des Produkts
+    Product,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PanelCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// Einstellungen für das Produkt-Info-Panel (`interaction.info_panel`)
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct InfoPanelConfig {
+    pub enabled: bool,
+    pub anchor: PanelAnchor,
+    pub corner: PanelCorner,
+    /// Abstand zum Bildschirmrand in Pixeln
+    pub margin_px: f32,
+    /// Versatz zur Produktposition bei `anchor: product`
+    pub product_offset_px: [f32; 2],
+    pub width_px: f32,
+    pub title_font_size: f32,
+    pub body_font_size: f32,
+    /// RGBA
+    pub background: [f32; 4],
+    pub qr_size_px: f32,
+    /// Dauer der Ein-/Ausblendung
+    pub animation_secs: f32,
+    /// Strecke, die das Panel beim Einblenden gleitet
+    pub slide_px: f32,
+    /// Fallback-URL für Produkte ohne `shop_url` (`{id}` wird ersetzt)
+    pub shop_url_template: String,
+}
+
+impl Default for InfoPanelConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            anchor: PanelAnchor::Screen,
+            corner: PanelCorner::BottomRight,
+            margin_px: 32.0,
+            product_offset_px: [48.0, -48.0],
+            width_px: 360.0,
+            title_font_size: 32.0,
+            body_font_size: 18.0,
+            background: [0.05, 0.05, 0.08, 0.85],
+            qr_size_px: 140.0,
+            animation_secs: 0.35,
+            slide_px: 40.0,
+            shop_url_template: "https://shop.example.com/produkte/{id}".to_string(),
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct InfoPanel;
+
+#[derive(Component)]
+pub struct InfoPanelName;
+
+#[derive(Component)]
+pub struct InfoPanelPrice;
+
+#[derive(Component)]
+pub struct InfoPanelDescription;
+
+#[derive(Component)]
+pub struct InfoPanelQr;
+
+/// Angezeigtes Produkt und Fortschritt der Ein-/Ausblendung (0 = unsichtbar, 1 = sichtbar)
+#[derive(Resource, Default)]
+pub struct InfoPanelState {
+    product: Option<Entity>,
+    progress: f32,
+}
+
+/// Legt das (unsichtbare) Panel einmalig an; Layout-Werte folgen der Konfiguration
+pub fn spawn_info_panel(mut commands: Commands, asset_server: Res<AssetServer>, config: Res<AppConfig>) {
+    let panel = &config.interaction.info_panel;
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(panel.width_px),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(8.0),
+            padding: UiRect::all(Val::Px(16.0)),
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+        Visibility::Hidden,
+        InfoPanel,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(""),
+            TextFont { font: font.clone(), font_size: panel.title_font_size, ..default() },
+            TextColor(Color::WHITE),
+            InfoPanelName,
+        ));
+        parent.spawn((
+            Text::new(""),
+            TextFont { font: font.clone(), font_size: panel.body_font_size * 1.2, ..default() },
+            TextColor(Color::srgb(1.0, 0.8, 0.3)),
+            InfoPanelPrice,
+        ));
+        parent.spawn((
+            Text::new(""),
+            TextFont { font, font_size: panel.body_font_size, ..default() },
+            TextColor(Color::srgb(0.85, 0.85, 0.85)),
+            InfoPanelDescription,
+        ));
+        parent.spawn((
+            Node {
+                width: Val::Px(panel.qr_size_px),
+                height: Val::Px(panel.qr_size_px),
+                margin: UiRect::top(Val::Px(8.0)),
+                ..default()
+            },
+            ImageNode::default(),
+            InfoPanelQr,
+        ));
+    });
+}
+
+/// Füllt das Panel bei jeder Auswahländerung mit den Katalogdaten
+pub fn update_info_panel_content(
+    mut selected_reader: MessageReader<ProductSelected>,
+    config: Res<AppConfig>,
+    catalog: Res<ProductCatalog>,
+    products: Query<&Product>,
+    mut images: ResMut<Assets<Image>>,
+    mut state: ResMut<InfoPanelState>,
+    mut name_text: Query<&mut Text, (With<InfoPanelName>, Without<InfoPanelPrice>, Without<InfoPanelDescription>)>,
+    mut price_text: Query<&mut Text, (With<InfoPanelPrice>, Without<InfoPanelName>, Without<InfoPanelDescription>)>,
+    mut description_text: Query<&mut Text, (With<InfoPanelDescription>, Without<InfoPanelName>, Without<InfoPanelPrice>)>,
+    mut qr_image: Query<&mut ImageNode, With<InfoPanelQr>>,
+) {
+    let Some(selected) = selected_reader.read().last() else {
+        return;
+    };
+    let panel = &config.interaction.info_panel;
+
+    let entry = selected
+        .entity
+        .and_then(|entity| products.get(entity).ok())
+        .and_then(|product| catalog.get(&product.id));
+    let Some(entry) = entry.filter(|_| panel.enabled) else {
+        // Kein Katalogprodukt: Panel blendet aus
+        state.product = None;
+        return;
+    };
+    state.product = selected.entity;
+
+    for mut text in &mut name_text {
+        **text = entry.name.clone();
+    }
+    for mut text in &mut price_text {
+        **text = entry.formatted_price();
+    }
+    for mut text in &mut description_text {
+        **text = entry.description.clone();
+    }
+
+    let url = entry
+        .shop_url
+        .clone()
+        .unwrap_or_else(|| panel.shop_url_template.replace("{id}", &entry.id));
+    match qr_code_image(&url) {
+        Some(image) => {
+            let handle = images.add(image);
+            for mut node in &mut qr_image {
+                node.image = handle.clone();
+            }
+        }
+        None => warn!("QR-Code für '{}' konnte nicht erzeugt werden", url),
+    }
+}
+
+/// Rendert einen QR-Code als Textur (ein Pixel pro Modul, Ruhezone inklusive)
+fn qr_code_image(url: &str) -> Option<Image> {
+    const QUIET_ZONE: usize = 2;
+    let code = QrCode::new(url.as_bytes()).ok()?;
+    let modules = code.width();
+    let size = modules + QUIET_ZONE * 2;
+    let colors = code.to_colors();
+
+    let mut data = vec![255u8; size * size * 4];
+    for y in 0..modules {
+        for x in 0..modules {
+            if colors[y * modules + x] == qrcode::Color::Dark {
+                let index = ((y + QUIET_ZONE) * size + x + QUIET_ZONE) * 4;
+                data[index..index + 3].fill(0);
+            }
+        }
+    }
+
+    let mut image = Image::new(
+        Extent3d { width: size as u32, height: size as u32, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    );
+    // Scharfe Modulkanten statt verschwommener Interpolation
+    image.sampler = ImageSampler::nearest();
+    Some(image)
+}
+
+/// Blendet das Panel ein/aus und platziert es an der Ecke oder neben dem Produkt
+pub fn animate_info_panel(
+    config: Res<AppConfig>,
+    time: Res<Time>,
+    mut state: ResMut<InfoPanelState>,
+    windows: Query<&Window>,
+    cameras: Query<(&Camera, &GlobalTransform), With<OrbitCamera>>,
+    transforms: Query<&GlobalTransform>,
+    mut panels: Query<(&mut Node, &mut BackgroundColor, &mut Visibility), With<InfoPanel>>,
+    mut texts: Query<&mut TextColor, Or<(With<InfoPanelName>, With<InfoPanelPrice>, With<InfoPanelDescription>)>>,
+    mut qr_image: Query<&mut ImageNode, With<InfoPanelQr>>,
+) {
+    let panel = &config.interaction.info_panel;
+
+    // Verschwindet das Produkt (z.B. despawnt), verschwindet auch das Panel
+    let product_position = state.product.and_then(|entity| transforms.get(entity).ok()).map(|t| t.translation());
+    if product_position.is_none() {
+        state.product = None;
+    }
+
+    let target = if state.product.is_some() { 1.0 } else { 0.0 };
+    let step = time.delta_secs() / panel.animation_secs.max(0.01);
+    state.progress = if target > state.progress {
+        (state.progress + step).min(target)
+    } else {
+        (state.progress - step).max(target)
+    };
+    // Ease-out: schnell rein, sanft zur Ruhe
+    let eased = 1.0 - (1.0 - state.progress).powi(3);
+    let slide = (1.0 - eased) * panel.slide_px;
+
+    let projected = match (panel.anchor, product_position, cameras.single()) {
+        (PanelAnchor::Product, Some(position), Ok((camera, camera_transform))) => {
+            camera.world_to_viewport(camera_transform, position).ok()
+        }
+        _ => None,
+    };
+    let window_size = windows.iter().next().map_or(Vec2::ZERO, |window| window.size());
+
+    for (mut node, mut background, mut visibility) in &mut panels {
+        *visibility = if state.progress > 0.0 { Visibility::Visible } else { Visibility::Hidden };
+        let [r, g, b, a] = panel.background;
+        background.0 = srgb([r, g, b]).with_alpha(a * eased);
+        node.width = Val::Px(panel.width_px);
+
+        match projected {
+            Some(screen) => {
+                // Neben dem Produkt, aber innerhalb des Fensters
+                let max_x = (window_size.x - panel.width_px - panel.margin_px).max(panel.margin_px);
+                let x = (screen.x + panel.product_offset_px[0]).clamp(panel.margin_px, max_x);
+                let y = (screen.y + panel.product_offset_px[1]).max(panel.margin_px);
+                node.left = Val::Px(x + slide);
+                node.top = Val::Px(y);
+                node.right = Val::Auto;
+                node.bottom = Val::Auto;
+            }
+            None => {
+                let margin = Val::Px(panel.margin_px);
+                let (left, right) = match panel.corner {
+                    PanelCorner::TopLeft | PanelCorner::BottomLeft => (Val::Px(panel.margin_px - slide), Val::Auto),
+                    PanelCorner::TopRight | PanelCorner::BottomRight => (Val::Auto, Val::Px(panel.margin_px - slide)),
+                };
+                let (top, bottom) = match panel.corner {
+                    PanelCorner::TopLeft | PanelCorner::TopRight => (margin, Val::Auto),
+                    PanelCorner::BottomLeft | PanelCorner::BottomRight => (Val::Auto, margin),
+                };
+                node.left = left;
+                node.right = right;
+                node.top = top;
+                node.bottom = bottom;
+            }
+        }
+    }
+
+    for mut color in &mut texts {
+        color.0.set_alpha(eased);
+    }
+    for mut node in &mut qr_image {
+        node.color = Color::WHITE.with_alpha(eased);
+    }
+}
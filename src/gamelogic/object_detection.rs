@@ -0,0 +1,188 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::gamelogic::selection::Selectable;
+use crate::network::GameEvent;
+use crate::setup::asset_registry::AssetRegistry;
+use crate::setup::config::AppConfig;
+use crate::setup::gltf_spawner::{spawn_gltf_with_physics, GltfSpawnConfig};
+use crate::setup::orbiting_camera::OrbitCamera;
+use crate::setup::world::RadialGravity;
+
+/// Einstellungen für erkannte Objekte (`interaction.detection`)
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct DetectionConfig {
+    pub enabled: bool,
+    /// Klassenname des Detektors (z.B. "cup") -> Modell-Key (z.B. "tasse")
+    pub class_models: HashMap<String, String>,
+    /// Nach dieser Zeit ohne Erkennung wird das Produkt entfernt
+    pub timeout_secs: f32,
+    /// Spiegelt die x-Achse der Detektor-Koordinaten
+    pub mirror_x: bool,
+    /// Wie schnell das Produkt seiner erkannten Position folgt (1/s)
+    pub follow_speed: f32,
+    pub mass: f32,
+}
+
+impl Default for DetectionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            class_models: HashMap::from([("cup".to_string(), "tasse".to_string())]),
+            timeout_secs: 2.0,
+            mirror_x: false,
+            follow_speed: 4.0,
+            mass: 1.0,
+        }
+    }
+}
+
+/// Produkt, das ein real erkanntes Objekt repräsentiert.
+///
+/// Objekte werden nur über `class_name` zugeordnet: pro Klasse gibt es höchstens ein
+/// Produkt. Erkennt der Detektor zwei Tassen, springt die eine Tasse zwischen beiden Positionen.
+#[derive(Component, Debug)]
+pub struct DetectedObject {
+    pub class_name: String,
+    /// Auf die Szene projizierte Zielposition
+    pub target: Vec3,
+    last_seen: f32,
+}
+
+/// Spawnt bzw. bewegt Produkte für `GameEvent::ObjectDetected` (eins pro Klasse, siehe `DetectedObject`)
+pub fn spawn_detected_objects(
+    mut commands: Commands,
+    mut event_reader: MessageReader<GameEvent>,
+    config: Res<AppConfig>,
+    time: Res<Time>,
+    registry: Option<Res<AssetRegistry>>,
+    gltf_assets: Res<Assets<Gltf>>,
+    gltf_mesh_assets: Res<Assets<bevy::gltf::GltfMesh>>,
+    mesh_assets: Res<Assets<Mesh>>,
+    camera_query: Query<(&Camera, &GlobalTransform, &OrbitCamera)>,
+    mut detected: Query<&mut DetectedObject>,
+    mut warned: Local<Vec<String>>,
+) {
+    let detection = &config.interaction.detection;
+    let now = time.elapsed_secs();
+    // Neue Klassen erst nach der Schleife spawnen; mehrere Events im selben Frame ergeben ein Produkt
+    let mut new_objects: HashMap<String, (String, Vec3)> = HashMap::new();
+
+    for event in event_reader.read() {
+        let GameEvent::ObjectDetected { name, x, y } = event else {
+            continue;
+        };
+        if !detection.enabled {
+            continue;
+        }
+        let Some(model_key) = detection.class_models.get(name) else {
+            if !warned.contains(name) {
+                debug!("Kein Modell für erkannte Klasse '{}' konfiguriert", name);
+                warned.push(name.clone());
+            }
+            continue;
+        };
+
+        let Ok((camera, camera_transform, orbit)) = camera_query.single() else {
+            continue;
+        };
+        let Some(target) = unproject_detection(camera, camera_transform, orbit.target, *x, *y, detection.mirror_x)
+        else {
+            continue;
+        };
+
+        // Bekanntes Objekt nur verschieben
+        if let Some(mut object) = detected.iter_mut().find(|object| &object.class_name == name) {
+            object.target = target;
+            object.last_seen = now;
+            continue;
+        }
+        new_objects.insert(name.clone(), (model_key.clone(), target));
+    }
+
+    let Some(registry) = registry.as_ref() else {
+        return;
+    };
+    for (name, (model_key, target)) in new_objects {
+        let model = match registry.model(&model_key) {
+            Ok(model) => model,
+            Err(e) => {
+                if !warned.contains(&name) {
+                    warn!("Erkanntes Objekt '{}' kann nicht gespawnt werden: {}", name, e);
+                    warned.push(name);
+                }
+                continue;
+            }
+        };
+
+        let spawn_config = GltfSpawnConfig::new(model.visual.clone())
+            .with_collider_gltf(model.collider_or_visual())
+            .with_transform(Transform::from_translation(target))
+            .with_mass(detection.mass)
+            .with_radial_gravity(false);
+
+        // Gibt None zurück, solange das GLTF noch lädt -> beim nächsten Event erneut
+        if let Some(entity) = spawn_gltf_with_physics(
+            &mut commands,
+            &gltf_assets,
+            &gltf_mesh_assets,
+            &mesh_assets,
+            spawn_config,
+            1.0,
+            None::<RadialGravity>,
+        ) {
+            commands.entity(entity).insert((
+                Name::new(format!("Detected {}", name)),
+                DetectedObject {
+                    class_name: name.clone(),
+                    target,
+                    last_seen: now,
+                },
+                Selectable,
+            ));
+            info!("📦 Erkanntes Objekt '{}' als '{}' gespawnt", name, model_key);
+        }
+    }
+}
+
+/// Zieht erkannte Produkte zu ihrer Zielposition und entfernt verschwundene Objekte
+pub fn follow_detected_objects(
+    mut commands: Commands,
+    config: Res<AppConfig>,
+    time: Res<Time>,
+    mut objects: Query<(Entity, &DetectedObject, &Transform, &mut LinearVelocity)>,
+) {
+    let detection = &config.interaction.detection;
+    let now = time.elapsed_secs();
+
+    for (entity, object, transform, mut velocity) in objects.iter_mut() {
+        if now - object.last_seen > detection.timeout_secs {
+            info!("📦 Objekt '{}' nicht mehr erkannt, entferne Produkt", object.class_name);
+            commands.entity(entity).despawn();
+            continue;
+        }
+        velocity.0 = (object.target - transform.translation) * detection.follow_speed;
+    }
+}
+
+/// Projiziert normalisierte Detektor-Koordinaten (0..1, Ursprung oben links)
+/// auf die Ebene durch das Kamera-Ziel
+fn unproject_detection(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    plane_origin: Vec3,
+    x: f32,
+    y: f32,
+    mirror_x: bool,
+) -> Option<Vec3> {
+    let viewport_size = camera.logical_viewport_size()?;
+    let x = if mirror_x { 1.0 - x } else { x };
+    let viewport_position = Vec2::new(x, y).clamp(Vec2::ZERO, Vec2::ONE) * viewport_size;
+    let ray = camera.viewport_to_world(camera_transform, viewport_position).ok()?;
+    let plane = InfinitePlane3d::new(camera_transform.back());
+    let distance = ray.intersect_plane(plane_origin, plane)?;
+    Some(ray.get_point(distance))
+}
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use rosc::OscType;
+
+use crate::gamelogic::selection::ProductSelected;
+use crate::network::{GameEvent, OscOutgoing};
+use crate::setup::appstate::AppState;
+use crate::setup::audio::CollisionImpact;
+use crate::setup::config::AppConfig;
+
+/// Meldet Auswahl, starke Kollisionen, Zustands- und Handanzahl-Wechsel per OSC nach außen
+pub fn emit_osc_feedback(
+    config: Res<AppConfig>,
+    state: Res<State<AppState>>,
+    mut selected_reader: MessageReader<ProductSelected>,
+    mut impact_reader: MessageReader<CollisionImpact>,
+    mut event_reader: MessageReader<GameEvent>,
+    mut osc_writer: MessageWriter<OscOutgoing>,
+    names: Query<&Name>,
+    mut last_hand_count: Local<Option<i32>>,
+) {
+    let output = &config.network.osc_output;
+    let mut send = |address: &str, args: Vec<OscType>| {
+        osc_writer.write(OscOutgoing { address: address.to_string(), args });
+    };
+
+    for selected in selected_reader.read() {
+        if output.product_selected.enabled {
+            let name = selected
+                .entity
+                .and_then(|e| names.get(e).ok())
+                .map(|name| name.to_string())
+                .unwrap_or_default();
+            send(
+                &output.product_selected.address,
+                vec![OscType::Bool(selected.entity.is_some()), OscType::String(name)],
+            );
+        }
+    }
+
+    for impact in impact_reader.read() {
+        if output.collision.enabled && impact.impulse >= output.collision_min_impulse {
+            send(
+                &output.collision.address,
+                vec![
+                    OscType::Float(impact.impulse),
+                    OscType::Float(impact.point.x),
+                    OscType::Float(impact.point.y),
+                    OscType::Float(impact.point.z),
+                ],
+            );
+        }
+    }
+
+    if state.is_changed() && output.state_changed.enabled {
+        send(&output.state_changed.address, vec![OscType::String(format!("{:?}", state.get()))]);
+    }
+
+    // Tracking sendet die Handanzahl laufend -> nur echte Wechsel melden
+    for event in event_reader.read() {
+        if let GameEvent::HandCountChanged(count) = event {
+            if *last_hand_count != Some(*count) {
+                *last_hand_count = Some(*count);
+                if output.hand_count_changed.enabled {
+                    send(&output.hand_count_changed.address, vec![OscType::Int(*count)]);
+                }
+            }
+        }
+    }
+}
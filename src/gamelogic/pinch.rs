@@ -0,0 +1,199 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::gamelogic::selection::Selection;
+use crate::network::GameEvent;
+use crate::setup::config::AppConfig;
+use crate::setup::orbiting_camera::OrbitCamera;
+
+/// Einstellungen für Pinch-to-Scale / Pinch-to-Zoom (`interaction.pinch`)
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct PinchConfig {
+    pub enabled: bool,
+    /// Nur diese Hände dürfen pinchen (leer = alle)
+    pub hands: Vec<i32>,
+    /// Glättungsfaktor pro Event (0 = eingefroren, 1 = ungefiltert)
+    pub smoothing: f32,
+    /// Änderungen unterhalb dieses Abstands zum Startwert werden ignoriert
+    pub dead_zone: f32,
+    /// Der Ausgabewert folgt erst, wenn er sich um mehr als diesen Wert ändert
+    pub hysteresis: f32,
+    /// Nach dieser Zeit ohne Pinch-Event wird die Geste beendet
+    pub release_timeout_secs: f32,
+    /// Skalierungsgrenzen relativ zur Originalgröße des Produkts
+    pub min_scale: f32,
+    pub max_scale: f32,
+    pub zoom_sensitivity: f32,
+}
+
+impl Default for PinchConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            hands: Vec::new(),
+            smoothing: 0.3,
+            dead_zone: 0.02,
+            hysteresis: 0.005,
+            release_timeout_secs: 0.3,
+            min_scale: 0.5,
+            max_scale: 3.0,
+            zoom_sensitivity: 1.0,
+        }
+    }
+}
+
+/// Originalgröße eines per Pinch skalierten Produkts
+#[derive(Component)]
+pub struct OriginalScale(pub Vec3);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PinchTarget {
+    Product(Entity),
+    Camera,
+}
+
+struct HandPinchFilter {
+    smoothed: f32,
+    latched: f32,
+    last_seen: f32,
+}
+
+struct ActivePinch {
+    hand_id: i32,
+    target: PinchTarget,
+    anchor: f32,
+    /// Skalierungsfaktor bzw. Kamera-Radius beim Start der Geste
+    base_value: f32,
+    /// Originalgröße des Produkts, beim Verankern festgehalten
+    original_scale: Vec3,
+}
+
+/// Gefilterte Pinch-Werte pro Hand und die aktuell steuernde Hand
+#[derive(Resource, Default)]
+pub struct PinchState {
+    filters: HashMap<i32, HandPinchFilter>,
+    active: Option<ActivePinch>,
+}
+
+pub fn apply_pinch_interaction(
+    mut commands: Commands,
+    mut event_reader: MessageReader<GameEvent>,
+    config: Res<AppConfig>,
+    time: Res<Time>,
+    selection: Res<Selection>,
+    mut state: ResMut<PinchState>,
+    mut products: Query<(&mut Transform, Option<&OriginalScale>), Without<OrbitCamera>>,
+    mut cameras: Query<&mut OrbitCamera>,
+) {
+    let pinch = &config.interaction.pinch;
+    let now = time.elapsed_secs();
+    if !pinch.enabled {
+        event_reader.clear();
+        return;
+    }
+
+    for event in event_reader.read() {
+        let GameEvent::HandPinchDistance { hand_id, distance } = event else {
+            continue;
+        };
+        if !pinch.hands.is_empty() && !pinch.hands.contains(hand_id) {
+            continue;
+        }
+        if !distance.is_finite() || *distance <= 0.0 {
+            continue;
+        }
+
+        // Glätten + Hysterese pro Hand
+        let filter = state.filters.entry(*hand_id).or_insert(HandPinchFilter {
+            smoothed: *distance,
+            latched: *distance,
+            last_seen: now,
+        });
+        filter.smoothed += (distance - filter.smoothed) * pinch.smoothing.clamp(0.0, 1.0);
+        if (filter.smoothed - filter.latched).abs() > pinch.hysteresis {
+            filter.latched = filter.smoothed;
+        }
+        filter.last_seen = now;
+        let latched = filter.latched;
+
+        let target = match selection.selected {
+            Some(entity) if products.contains(entity) => PinchTarget::Product(entity),
+            _ => PinchTarget::Camera,
+        };
+
+        // Neue Geste starten bzw. bei Zielwechsel neu verankern
+        let needs_anchor = match &state.active {
+            None => true,
+            Some(active) => active.hand_id == *hand_id && active.target != target,
+        };
+        if needs_anchor {
+            let (base_value, original_scale) = match target {
+                PinchTarget::Product(entity) => {
+                    let Ok((transform, original)) = products.get(entity) else { continue };
+                    let original_scale = match original {
+                        Some(original) => original.0,
+                        None => {
+                            commands.entity(entity).insert(OriginalScale(transform.scale));
+                            transform.scale
+                        }
+                    };
+                    let base_value = if original_scale.x.abs() > f32::EPSILON {
+                        transform.scale.x / original_scale.x
+                    } else {
+                        1.0
+                    };
+                    (base_value, original_scale)
+                }
+                PinchTarget::Camera => match cameras.single() {
+                    Ok(orbit) => (orbit.radius, Vec3::ONE),
+                    Err(_) => continue,
+                },
+            };
+            state.active = Some(ActivePinch {
+                hand_id: *hand_id,
+                target,
+                anchor: latched,
+                base_value,
+                original_scale,
+            });
+        }
+
+        let Some(active) = state.active.as_ref() else { continue };
+        if active.hand_id != *hand_id {
+            continue; // Andere Hand steuert gerade
+        }
+
+        // Dead-Zone um den Startwert
+        let delta = latched - active.anchor;
+        if delta.abs() < pinch.dead_zone {
+            continue;
+        }
+        let ratio = latched / active.anchor;
+
+        match active.target {
+            PinchTarget::Product(entity) => {
+                let Ok((mut transform, _)) = products.get_mut(entity) else { continue };
+                let factor = (active.base_value * ratio).clamp(pinch.min_scale, pinch.max_scale);
+                transform.scale = active.original_scale * factor;
+            }
+            PinchTarget::Camera => {
+                for mut orbit in cameras.iter_mut() {
+                    // Finger auseinander -> näher heran
+                    let radius = active.base_value / ratio.powf(pinch.zoom_sensitivity);
+                    orbit.radius = radius.clamp(orbit.min_radius, orbit.max_radius);
+                }
+            }
+        }
+    }
+
+    // Hände ohne aktuelle Pinch-Events freigeben
+    let timeout = pinch.release_timeout_secs;
+    state.filters.retain(|_, filter| now - filter.last_seen <= timeout);
+    if let Some(hand_id) = state.active.as_ref().map(|active| active.hand_id) {
+        if !state.filters.contains_key(&hand_id) {
+            state.active = None;
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use bevy::prelude::*;
+
+use crate::network::{RemoteCommand, ServerMessage};
+use crate::setup::audio::AmbienceCommand;
+use crate::setup::diagnostics::DiagnosticsOverlayVisible;
+use crate::setup::orbiting_camera::{AutoOrbit, OrbitCamera};
+use crate::setup::particles::ParticleBurst;
+use crate::setup::presets::{ScenePresets, SwitchScenePreset};
+use crate::setup::snapshot::SceneSnapshot;
+use crate::setup::stresstest::StressTestConfig;
+
+/// Alle Systemparameter, die Fernsteuerungs-Befehle verändern dürfen
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct RemoteCommandContext<'w, 's> {
+    cameras: Query<'w, 's, (&'static mut OrbitCamera, &'static mut AutoOrbit)>,
+    stress_test: ResMut<'w, StressTestConfig>,
+    overlay: ResMut<'w, DiagnosticsOverlayVisible>,
+    ambience_writer: MessageWriter<'w, AmbienceCommand>,
+    burst_writer: MessageWriter<'w, ParticleBurst>,
+    presets: Res<'w, ScenePresets>,
+    preset_writer: MessageWriter<'w, SwitchScenePreset>,
+    snapshot_writer: MessageWriter<'w, SceneSnapshot>,
+}
+
+/// Führt `RemoteCommand`s aus (OSC und WebSocket) und bestätigt sie an die WebSocket-Clients
+pub fn apply_remote_commands(
+    mut command_reader: MessageReader<RemoteCommand>,
+    mut server_writer: MessageWriter<ServerMessage>,
+    mut context: RemoteCommandContext,
+) {
+    for command in command_reader.read() {
+        let result = execute_command(command, &mut context);
+        match &result {
+            Ok(()) => info!("🎛️ Befehl ausgeführt: {:?}", command),
+            Err(e) => warn!("🎛️ Befehl {:?} fehlgeschlagen: {}", command, e),
+        }
+        server_writer.write(ServerMessage::CommandAck {
+            cmd: command.name().to_string(),
+            ok: result.is_ok(),
+            message: result.err(),
+        });
+    }
+}
+
+fn execute_command(command: &RemoteCommand, context: &mut RemoteCommandContext) -> Result<(), String> {
+    match command {
+        RemoteCommand::SwitchPreset { name } => {
+            if context.presets.get(name).is_none() {
+                let available = context.presets.names();
+                return Err(format!(
+                    "Szenen-Preset '{}' unbekannt (verfügbar: {})",
+                    name,
+                    if available.is_empty() { "keine".to_string() } else { available.join(", ") }
+                ));
+            }
+            context.preset_writer.write(SwitchScenePreset { name: name.clone(), instant: false, lighting_scale: None });
+            Ok(())
+        }
+        RemoteCommand::SetCameraTarget { target } => {
+            let target = Vec3::from_array(*target);
+            if !target.is_finite() {
+                return Err("Ungültiges Kamera-Ziel".to_string());
+            }
+            for (mut orbit, _) in context.cameras.iter_mut() {
+                orbit.target = target;
+            }
+            Ok(())
+        }
+        RemoteCommand::SetOrbitSpeed { speed } => {
+            if !speed.is_finite() {
+                return Err("Ungültige Orbit-Geschwindigkeit".to_string());
+            }
+            for (_, mut auto_orbit) in context.cameras.iter_mut() {
+                auto_orbit.speed = *speed;
+            }
+            Ok(())
+        }
+        RemoteCommand::StartStressTest => {
+            context.stress_test.enabled = true;
+            Ok(())
+        }
+        RemoteCommand::StopStressTest => {
+            context.stress_test.enabled = false;
+            Ok(())
+        }
+        RemoteCommand::ToggleDiagnostics => {
+            context.overlay.visible = !context.overlay.visible;
+            Ok(())
+        }
+        RemoteCommand::SetAmbienceVolume { volume } => {
+            if !volume.is_finite() || *volume < 0.0 {
+                return Err(format!("Ungültige Lautstärke: {}", volume));
+            }
+            context.ambience_writer.write(AmbienceCommand::SetVolume(*volume));
+            Ok(())
+        }
+        RemoteCommand::ParticleBurst { position } => {
+            // Ohne Position am Kamera-Ziel
+            let position = position.map(Vec3::from_array).or_else(|| {
+                context.cameras.iter().next().map(|(orbit, _)| orbit.target)
+            });
+            context.burst_writer.write(ParticleBurst { position: position.unwrap_or(Vec3::ZERO) });
+            Ok(())
+        }
+        RemoteCommand::SaveSnapshot => {
+            context.snapshot_writer.write(SceneSnapshot::Save);
+            Ok(())
+        }
+        RemoteCommand::LoadSnapshot => {
+            context.snapshot_writer.write(SceneSnapshot::Load);
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use crate::setup::audio::PlaySound;
+
+/// Marker für Produkte, die ausgewählt und hervorgehoben werden können
+#[derive(Component)]
+pub struct Selectable;
+
+/// Aktuell ausgewähltes bzw. hervorgehobenes Produkt
+#[derive(Resource, Default)]
+pub struct Selection {
+    pub selected: Option<Entity>,
+    pub highlighted: Option<Entity>,
+}
+
+/// Wird gesendet, wenn sich die Auswahl ändert (`None` = Auswahl aufgehoben)
+#[derive(Message, Clone, Debug)]
+pub struct ProductSelected {
+    pub entity: Option<Entity>,
+}
+
+impl Selection {
+    /// Setzt die Auswahl und meldet die Änderung (inkl. Klick-Sound)
+    pub fn select(
+        &mut self,
+        entity: Option<Entity>,
+        selected_writer: &mut MessageWriter<ProductSelected>,
+        sound_writer: &mut MessageWriter<PlaySound>,
+    ) {
+        if self.selected == entity {
+            return;
+        }
+        self.selected = entity;
+        selected_writer.write(ProductSelected { entity });
+        if entity.is_some() {
+            sound_writer.write(PlaySound { name: "click".to_string() });
+        }
+    }
+}
+
+/// Produkt, das der Kamera-Blickrichtung am nächsten liegt
+pub fn product_in_focus(
+    camera_transform: &GlobalTransform,
+    products: impl Iterator<Item = (Entity, Vec3)>,
+) -> Option<Entity> {
+    let origin = camera_transform.translation();
+    let forward = camera_transform.forward();
+    products
+        .filter_map(|(entity, position)| {
+            let to_product = position - origin;
+            let distance = to_product.length();
+            if distance <= f32::EPSILON {
+                return None;
+            }
+            let alignment = to_product.dot(*forward) / distance;
+            (alignment > 0.0).then_some((entity, alignment))
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity)
+}
+
+/// Entfernt despawnte Produkte (Preset-Wechsel, Snapshot, verschwundenes Objekt) aus der Auswahl.
+/// Die Auswahl wird über `Selection::select` aufgehoben, damit WS- und OSC-Feedback davon erfahren.
+pub fn clear_despawned_selection(
+    mut selection: ResMut<Selection>,
+    mut selected_writer: MessageWriter<ProductSelected>,
+    mut sound_writer: MessageWriter<PlaySound>,
+    products: Query<(), With<Selectable>>,
+) {
+    if selection.selected.is_some_and(|e| !products.contains(e)) {
+        selection.select(None, &mut selected_writer, &mut sound_writer);
+    }
+    if selection.highlighted.is_some_and(|e| !products.contains(e)) {
+        selection.highlighted = None;
+    }
+}
+
+/// Hebt ausgewählte und hervorgehobene Produkte über ihre Collider-AABB hervor
+pub fn draw_selection_highlight(mut gizmos: Gizmos, selection: Res<Selection>, aabbs: Query<&ColliderAabb>) {
+    if let Some(Ok(aabb)) = selection.highlighted.map(|e| aabbs.get(e)) {
+        draw_aabb(&mut gizmos, aabb, Color::srgb(0.3, 0.7, 1.0));
+    }
+    if let Some(Ok(aabb)) = selection.selected.map(|e| aabbs.get(e)) {
+        draw_aabb(&mut gizmos, aabb, Color::srgb(1.0, 0.8, 0.1));
+    }
+}
+
+fn draw_aabb(gizmos: &mut Gizmos, aabb: &ColliderAabb, color: Color) {
+    let center = (aabb.min + aabb.max) * 0.5;
+    let size = (aabb.max - aabb.min) * 1.05;
+    gizmos.cuboid(Transform::from_translation(center).with_scale(size), color);
+}
@@ -0,0 +1,71 @@
+use bevy::diagnostic::{DiagnosticPath, DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin};
+use bevy::prelude::*;
+
+use crate::gamelogic::selection::ProductSelected;
+use crate::network::ServerMessage;
+use crate::setup::appstate::AppState;
+use crate::setup::catalog::Product;
+use crate::setup::config::AppConfig;
+
+/// Meldet Wechsel des `AppState` an die WebSocket-Clients
+pub fn broadcast_app_state(
+    state: Res<State<AppState>>,
+    mut server_writer: MessageWriter<ServerMessage>,
+) {
+    if state.is_changed() {
+        server_writer.write(ServerMessage::StateChanged {
+            state: format!("{:?}", state.get()),
+        });
+    }
+}
+
+/// Meldet das ausgewählte Produkt an die WebSocket-Clients
+pub fn broadcast_selected_product(
+    mut selected_reader: MessageReader<ProductSelected>,
+    mut server_writer: MessageWriter<ServerMessage>,
+    names: Query<&Name>,
+    products: Query<&Product>,
+) {
+    for selected in selected_reader.read() {
+        server_writer.write(ServerMessage::ProductSelected {
+            entity: selected.entity.map(|e| e.to_bits()),
+            name: selected
+                .entity
+                .and_then(|e| names.get(e).ok())
+                .map(|name| name.to_string()),
+            product_id: selected
+                .entity
+                .and_then(|e| products.get(e).ok())
+                .map(|product| product.id.clone()),
+        });
+    }
+}
+
+/// Schickt in festem Intervall FPS, Frame-Time und Entity-Anzahl
+pub fn broadcast_health(
+    config: Res<AppConfig>,
+    time: Res<Time>,
+    diagnostics: Res<DiagnosticsStore>,
+    state: Res<State<AppState>>,
+    mut server_writer: MessageWriter<ServerMessage>,
+    mut last_sent: Local<f32>,
+) {
+    let now = time.elapsed_secs();
+    if now - *last_sent < config.network.health_interval_secs {
+        return;
+    }
+    *last_sent = now;
+
+    let smoothed = |path: &DiagnosticPath| {
+        diagnostics
+            .get(path)
+            .and_then(|diagnostic| diagnostic.smoothed())
+            .unwrap_or(0.0)
+    };
+    server_writer.write(ServerMessage::Health {
+        fps: smoothed(&FrameTimeDiagnosticsPlugin::FPS),
+        frame_time_ms: smoothed(&FrameTimeDiagnosticsPlugin::FRAME_TIME),
+        entity_count: smoothed(&EntityCountDiagnosticsPlugin::ENTITY_COUNT) as u64,
+        state: format!("{:?}", state.get()),
+    });
+}
@@ -5,20 +5,51 @@ mod gamelogic;
 use bevy::prelude::*;
 use avian3d::prelude::*;
 use bevy_hanabi::HanabiPlugin;
-use crate::setup::appstate::AppState;
-use crate::network::{OscReceiverPlugin, WebSocketReceiverPlugin};
+use crate::setup::appstate::{AppState, InteractionMode};
+use crate::setup::catalog::{ProductCatalog, CATALOG_PATH};
+use crate::setup::config::{srgb, AppConfig, CONFIG_PATH};
+use crate::setup::presets::{ScenePresets, PRESETS_DIR};
+use crate::network::{EventRecordingPlugin, OscReceiverPlugin, OscSenderPlugin, TrackingSimulatorPlugin, WebSocketReceiverPlugin};
 use crate::gamelogic::GamelogicPlugin;
 
 fn main() {
+    let config = match AppConfig::load(CONFIG_PATH) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    };
+    let catalog = match ProductCatalog::load(CATALOG_PATH, &config.assets.models) {
+        Ok(catalog) => catalog,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    };
+    let presets = match ScenePresets::load_dir(PRESETS_DIR, &catalog) {
+        Ok(presets) => presets,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let present_mode = if config.window.vsync {
+        bevy::window::PresentMode::AutoVsync
+    } else {
+        bevy::window::PresentMode::AutoNoVsync
+    };
+
     let mut app = App::new();
     app.add_plugins(DefaultPlugins
         .set(WindowPlugin {
             primary_window: Some(Window {
-                title: "Interactive Storefront".to_string(),
-                present_mode: bevy::window::PresentMode::AutoVsync,
+                title: config.window.title.clone(),
+                present_mode,
                 mode: bevy::window::WindowMode::Windowed,
-                resolution: (1920, 1080).into(),
-                resizable: true,
+                resolution: (config.window.width, config.window.height).into(),
+                resizable: config.window.resizable,
                 ..default()
             }),
             ..default()
@@ -32,6 +63,10 @@ fn main() {
             ),
             ..default()
         })
+        .set(AssetPlugin {
+            watch_for_changes_override: Some(config.hot_reload),
+            ..default()
+        })
         .set(bevy::log::LogPlugin {
             filter: "wgpu=error,bevy_render=info,bevy_ecs=info,winit=error".to_string(),
             level: bevy::log::Level::INFO,
@@ -40,13 +75,29 @@ fn main() {
     );
     app.add_plugins(PhysicsPlugins::default());
     app.add_plugins(PhysicsDebugPlugin::default());
-    app.add_plugins(OscReceiverPlugin { listen_address: "0.0.0.0:9001".to_string(),});
-    app.add_plugins(WebSocketReceiverPlugin { listen_address: "0.0.0.0:9002".to_string(),});
+    app.add_plugins(OscReceiverPlugin {
+        listen_address: config.network.osc_listen_address.clone(),
+        bind_retry: config.network.bind_retry,
+    });
+    app.add_plugins(WebSocketReceiverPlugin {
+        listen_address: config.network.ws_listen_address.clone(),
+        bind_retry: config.network.bind_retry,
+    });
+    app.add_plugins(EventRecordingPlugin);
+    // Tracking-Simulator nur in Debug-Builds
+    if cfg!(debug_assertions) {
+        app.add_plugins(TrackingSimulatorPlugin);
+    }
+    app.add_plugins(OscSenderPlugin { targets: config.network.osc_output.targets.clone() });
     app.add_plugins(GamelogicPlugin);
     app.add_plugins(HanabiPlugin);
-    app.insert_resource(Gravity(Vec3::ZERO));
-    app.insert_resource(ClearColor(Color::srgb(0.6, 0.6, 0.6)));
+    app.insert_resource(Gravity(Vec3::from_array(config.physics.gravity)));
+    app.insert_resource(ClearColor(srgb(config.rendering.clear_color)));
+    app.insert_resource(config);
+    app.insert_resource(catalog);
+    app.insert_resource(presets);
     app.init_state::<AppState>();
+    app.add_sub_state::<InteractionMode>();
     app.configure_sets(
         Update,
         PhysicsSystems::StepSimulation.run_if(in_state(AppState::Running))
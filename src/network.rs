@@ -1,8 +1,20 @@
+mod commands;
 mod events;
 mod osc;
+mod osc_mapping;
+mod osc_sender;
+mod recording;
+mod simulator;
+mod status;
 mod ws;
 
+pub use commands::*;
 pub use events::*;
 pub use osc::*;
+pub use osc_mapping::*;
+pub use osc_sender::*;
+pub use recording::*;
+pub use simulator::*;
+pub use status::*;
 pub use ws::*;
 
@@ -0,0 +1,90 @@
+use bevy::prelude::*;
+use rosc::{OscMessage, OscType};
+use serde::Deserialize;
+
+/// Präfix aller Steuer-Adressen per OSC
+pub const OSC_COMMAND_PREFIX: &str = "/storefront/";
+
+/// Steuerbefehl von außen (Tablet, Dashboard, Show-Control)
+///
+/// Per WebSocket als JSON, z.B. `{"cmd": "set_orbit_speed", "speed": 0.2}`,
+/// per OSC unter `/storefront/...` (siehe `from_osc`).
+#[derive(Message, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "cmd", rename_all = "snake_case")]
+pub enum RemoteCommand {
+    SwitchPreset { name: String },
+    SetCameraTarget { target: [f32; 3] },
+    SetOrbitSpeed { speed: f32 },
+    StartStressTest,
+    StopStressTest,
+    ToggleDiagnostics,
+    SetAmbienceVolume { volume: f32 },
+    ParticleBurst {
+        #[serde(default)]
+        position: Option<[f32; 3]>,
+    },
+    SaveSnapshot,
+    LoadSnapshot,
+}
+
+impl RemoteCommand {
+    /// Name wie im JSON-Feld `cmd` (für Bestätigungen)
+    pub fn name(&self) -> &'static str {
+        match self {
+            RemoteCommand::SwitchPreset { .. } => "switch_preset",
+            RemoteCommand::SetCameraTarget { .. } => "set_camera_target",
+            RemoteCommand::SetOrbitSpeed { .. } => "set_orbit_speed",
+            RemoteCommand::StartStressTest => "start_stress_test",
+            RemoteCommand::StopStressTest => "stop_stress_test",
+            RemoteCommand::ToggleDiagnostics => "toggle_diagnostics",
+            RemoteCommand::SetAmbienceVolume { .. } => "set_ambience_volume",
+            RemoteCommand::ParticleBurst { .. } => "particle_burst",
+            RemoteCommand::SaveSnapshot => "save_snapshot",
+            RemoteCommand::LoadSnapshot => "load_snapshot",
+        }
+    }
+
+    /// Übersetzt eine OSC-Nachricht unter `/storefront/...` in einen Befehl.
+    /// `None`, wenn die Adresse kein Steuerbefehl ist.
+    pub fn from_osc(msg: &OscMessage) -> Option<Result<RemoteCommand, String>> {
+        let path = msg.addr.strip_prefix(OSC_COMMAND_PREFIX)?;
+        let command = match path {
+            "preset" => osc_string(&msg.args, 0).map(|name| RemoteCommand::SwitchPreset { name }),
+            "camera/target" => osc_vec3(&msg.args).map(|target| RemoteCommand::SetCameraTarget { target }),
+            "camera/orbit_speed" => osc_float(&msg.args, 0).map(|speed| RemoteCommand::SetOrbitSpeed { speed }),
+            "stresstest/start" => Ok(RemoteCommand::StartStressTest),
+            "stresstest/stop" => Ok(RemoteCommand::StopStressTest),
+            "diagnostics/toggle" => Ok(RemoteCommand::ToggleDiagnostics),
+            "ambience/volume" => osc_float(&msg.args, 0).map(|volume| RemoteCommand::SetAmbienceVolume { volume }),
+            "particles/burst" => Ok(RemoteCommand::ParticleBurst {
+                position: if msg.args.is_empty() { None } else { osc_vec3(&msg.args).ok() },
+            }),
+            "snapshot/save" => Ok(RemoteCommand::SaveSnapshot),
+            "snapshot/load" => Ok(RemoteCommand::LoadSnapshot),
+            _ => Err(format!("Unbekannter Befehl: {}", msg.addr)),
+        };
+        Some(command.map_err(|e| format!("{}: {}", msg.addr, e)))
+    }
+}
+
+fn osc_float(args: &[OscType], index: usize) -> Result<f32, String> {
+    match args.get(index) {
+        Some(OscType::Float(f)) => Ok(*f),
+        Some(OscType::Double(d)) => Ok(*d as f32),
+        Some(OscType::Int(i)) => Ok(*i as f32),
+        Some(other) => Err(format!("Argument {} ist keine Zahl: {:?}", index, other)),
+        None => Err(format!("Argument {} fehlt", index)),
+    }
+}
+
+fn osc_string(args: &[OscType], index: usize) -> Result<String, String> {
+    match args.get(index) {
+        Some(OscType::String(s)) => Ok(s.clone()),
+        Some(other) => Err(format!("Argument {} ist kein String: {:?}", index, other)),
+        None => Err(format!("Argument {} fehlt", index)),
+    }
+}
+
+fn osc_vec3(args: &[OscType]) -> Result<[f32; 3], String> {
+    Ok([osc_float(args, 0)?, osc_float(args, 1)?, osc_float(args, 2)?])
+}
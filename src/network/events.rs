@@ -1,10 +1,13 @@
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Event, Debug, Clone, Message)]
+#[derive(Event, Debug, Clone, Message, Serialize, Deserialize)]
 pub enum GameEvent {
     HandCountChanged(i32),
     HandGesture { hand_id: i32, gesture: String },
     HandPinchDistance { hand_id: i32, distance: f32 },
+    /// Normalisierte Bildschirmposition (0..1, Ursprung oben links)
+    HandPosition { hand_id: i32, x: f32, y: f32 },
     ObjectDetected { name: String, x: f32, y: f32 },
 }
\ No newline at end of file
@@ -1,41 +1,79 @@
 use bevy::prelude::*;
 use crossbeam_channel::{unbounded, Receiver, Sender};
-use rosc::{OscMessage, OscPacket, OscType};
-use std::net::{SocketAddrV4, UdpSocket};
-use std::str::FromStr;
+use rosc::{OscMessage, OscPacket};
+use std::io::ErrorKind;
+use std::net::{SocketAddr, UdpSocket};
 use std::thread;
 
+use crate::setup::config::AppConfig;
+
+use super::commands::RemoteCommand;
 use super::events::GameEvent;
+use super::osc_mapping::OscAddressMap;
+use super::recording::{EventRecorder, EventSource, GameEventSink};
+use super::status::{
+    register_listener, track_listener_thread, BindRetryConfig, ListenerState, NetworkError, NetworkStatus,
+    StatusChange, StatusReporter, SHUTDOWN_POLL_INTERVAL,
+};
+use super::ws::ServerMessage;
 pub struct OscReceiverPlugin {
     pub listen_address: String,
+    pub bind_retry: BindRetryConfig,
 }
 
+const OSC_LISTENER: &str = "OSC";
+
 impl Plugin for OscReceiverPlugin {
     fn build(&self, app: &mut App) {
         let (tx, rx) = unbounded::<OscMessage>();
+        let reporter = register_listener(app, OSC_LISTENER, &self.listen_address);
 
         app.insert_resource(OscMessageReceiver(rx))
+            .init_resource::<OscAddressMap>()
+            .init_resource::<EventRecorder>()
             // .add_event::<GameEvent>()
-            .add_systems(Update, process_osc_messages_system);
+            .add_message::<RemoteCommand>()
+            .add_message::<ServerMessage>()
+            .add_systems(Update, (rebuild_osc_address_map, process_osc_messages_system).chain());
 
-        start_osc_listener_thread(self.listen_address.clone(), tx);
+        if let Some(handle) = start_osc_listener_thread(self.listen_address.clone(), self.bind_retry, tx, reporter) {
+            track_listener_thread(app, OSC_LISTENER, handle);
+        }
     }
 }
 
 #[derive(Resource)]
 struct OscMessageReceiver(Receiver<OscMessage>);
 
-fn start_osc_listener_thread(listen_address: String, sender: Sender<OscMessage>) {
-    thread::spawn(move || {
-        let addr = SocketAddrV4::from_str(&listen_address)
-            .expect("Failed to parse OSC listen address");
-        let socket = UdpSocket::bind(addr).expect("Failed to bind OSC socket");
+fn start_osc_listener_thread(
+    listen_address: String,
+    bind_retry: BindRetryConfig,
+    sender: Sender<OscMessage>,
+    reporter: StatusReporter,
+) -> Option<thread::JoinHandle<()>> {
+    let thread_reporter = reporter.clone();
+    let spawned = thread::Builder::new().name("osc-listener".to_string()).spawn(move || {
+        let reporter = thread_reporter;
+        let socket = match bind_osc_socket(&listen_address, bind_retry, &reporter) {
+            Ok(socket) => socket,
+            Err(e) => {
+                reporter.fail(&e);
+                return;
+            }
+        };
+
+        // Mit Timeout, damit der Thread das Shutdown-Flag mitbekommt
+        if let Err(source) = socket.set_read_timeout(Some(SHUTDOWN_POLL_INTERVAL)) {
+            reporter.fail(&NetworkError::Runtime { listener: OSC_LISTENER, source });
+            return;
+        }
 
+        reporter.report(StatusChange::State(ListenerState::Listening));
         info!("OSC receiver listening on {}", listen_address);
 
         let mut buf = [0u8; 65536];
 
-        loop {
+        while !reporter.is_shutting_down() {
             match socket.recv_from(&mut buf) {
                 Ok((size, _addr)) => {
                     let packet = rosc::decoder::decode_udp(&buf[..size]);
@@ -61,78 +99,94 @@ fn start_osc_listener_thread(listen_address: String, sender: Sender<OscMessage>)
                         }
                     }
                 }
+                Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
                 Err(e) => {
                     error!("Failed to receive OSC packet: {}", e);
                 }
             }
         }
+
+        info!("OSC receiver on {} stopped", listen_address);
+        reporter.report(StatusChange::State(ListenerState::Stopped));
     });
+    spawned
+        .map_err(|source| reporter.fail(&NetworkError::Runtime { listener: OSC_LISTENER, source }))
+        .ok()
+}
+
+/// Bindet den UDP-Socket; ein belegter Port wird mit Backoff erneut versucht
+fn bind_osc_socket(
+    listen_address: &str,
+    bind_retry: BindRetryConfig,
+    reporter: &StatusReporter,
+) -> Result<UdpSocket, NetworkError> {
+    let addr: SocketAddr = listen_address.parse().map_err(|source| NetworkError::InvalidAddress {
+        listener: OSC_LISTENER,
+        address: listen_address.to_string(),
+        source,
+    })?;
+
+    let mut attempt = 0;
+    loop {
+        match UdpSocket::bind(addr) {
+            Ok(socket) => return Ok(socket),
+            Err(source) => {
+                attempt += 1;
+                if attempt >= bind_retry.attempts.max(1) || reporter.is_shutting_down() {
+                    return Err(NetworkError::Bind {
+                        listener: OSC_LISTENER,
+                        address: listen_address.to_string(),
+                        attempts: attempt,
+                        source,
+                    });
+                }
+                let delay = bind_retry.delay(attempt - 1);
+                warn!("Bind an OSC-Port {} fehlgeschlagen ({}), neuer Versuch in {:?}", addr, source, delay);
+                reporter.report(StatusChange::State(ListenerState::Retrying { attempt }));
+                thread::sleep(delay);
+            }
+        }
+    }
+}
+
+/// Übernimmt `network.osc_mappings` beim Start und nach Änderungen der settings.json
+fn rebuild_osc_address_map(config: Res<AppConfig>, mut address_map: ResMut<OscAddressMap>) {
+    if config.is_changed() {
+        *address_map = OscAddressMap::from_mappings(&config.network.osc_mappings);
+        info!("OSC-Mapping geladen ({} Adressen)", config.network.osc_mappings.len());
+    }
 }
 
 fn process_osc_messages_system(
     receiver: Res<OscMessageReceiver>,
-    mut event_writer: MessageWriter<GameEvent>,
+    time: Res<Time>,
+    mut status: ResMut<NetworkStatus>,
+    mut address_map: ResMut<OscAddressMap>,
+    mut event_sink: GameEventSink,
+    mut command_writer: MessageWriter<RemoteCommand>,
+    mut server_writer: MessageWriter<ServerMessage>,
 ) {
     while let Ok(msg) = receiver.0.try_recv() {
-        match msg.addr.as_str() {
-            "/hand/count" => {
-                if let Some(OscType::Int(count)) = msg.args.first() {
-                    event_writer.write(GameEvent::HandCountChanged(*count));
-                }
+        status.touch(OSC_LISTENER, time.elapsed_secs());
+        match RemoteCommand::from_osc(&msg) {
+            Some(Ok(command)) => {
+                command_writer.write(command);
+                continue;
             }
-            "/hand/gesture" => {
-                if let (Some(OscType::Int(hand_id)), Some(gesture)) =
-                    (msg.args.get(0), msg.args.get(1))
-                {
-                    if let OscType::String(gesture_str) = gesture {
-                        event_writer.write(GameEvent::HandGesture {
-                            hand_id: *hand_id,
-                            gesture: gesture_str.clone(),
-                        });
-                    }
-                }
-            }
-            "/hand/pinch" => {
-                if let (Some(OscType::Int(hand_id)), Some(distance)) =
-                    (msg.args.get(0), msg.args.get(1))
-                {
-                    let dist_f32 = match distance {
-                        OscType::Float(f) => *f,
-                        OscType::Double(d) => *d as f32,
-                        _ => continue,
-                    };
-                    event_writer.write(GameEvent::HandPinchDistance {
-                        hand_id: *hand_id,
-                        distance: dist_f32,
-                    });
-                }
-            }
-            "/object/detected" => {
-                if let (Some(name), Some(x), Some(y)) =
-                    (msg.args.get(0), msg.args.get(1), msg.args.get(2))
-                {
-                    if let OscType::String(name_str) = name {
-                        let x_f32 = match x {
-                            OscType::Float(f) => *f,
-                            OscType::Double(d) => *d as f32,
-                            _ => continue,
-                        };
-                        let y_f32 = match y {
-                            OscType::Float(f) => *f,
-                            OscType::Double(d) => *d as f32,
-                            _ => continue,
-                        };
-                        event_writer.write(GameEvent::ObjectDetected {
-                            name: name_str.clone(),
-                            x: x_f32,
-                            y: y_f32,
-                        });
-                    }
-                }
-            }
-            _ => {
-                warn!("Unknown OSC address: {}", msg.addr);
+            Some(Err(e)) => {
+                warn!("Ungültiger OSC-Befehl: {}", e);
+                server_writer.write(ServerMessage::CommandAck {
+                    cmd: msg.addr.clone(),
+                    ok: false,
+                    message: Some(e),
+                });
+                continue;
             }
+            None => {}
+        }
+
+        for event in address_map.events_for(&msg) {
+            event_sink.send(EventSource::Osc, event);
         }
     }
 }
\ No newline at end of file
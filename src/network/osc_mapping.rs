@@ -0,0 +1,340 @@
+use bevy::prelude::*;
+use rosc::address::{Matcher, OscAddress};
+use rosc::{OscMessage, OscType};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+
+use super::events::GameEvent;
+
+/// Höchstens so viele unbekannte Adressen werden gemerkt und gemeldet (pro Aufbau der Zuordnung)
+const MAX_WARNED_ADDRESSES: usize = 64;
+
+/// `GameEvent`-Variante, auf die eine OSC-Adresse abgebildet wird
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OscEventKind {
+    HandCountChanged,
+    HandGesture,
+    HandPinchDistance,
+    HandPosition,
+    ObjectDetected,
+}
+
+impl OscEventKind {
+    /// Standard-Argumentpositionen, wenn in der Konfiguration nichts angegeben ist
+    fn default_position(&self, field: &str) -> Option<usize> {
+        let fields: &[&str] = match self {
+            OscEventKind::HandCountChanged => &["count"],
+            OscEventKind::HandGesture => &["hand_id", "gesture"],
+            OscEventKind::HandPinchDistance => &["hand_id", "distance"],
+            OscEventKind::HandPosition => &["hand_id", "x", "y"],
+            OscEventKind::ObjectDetected => &["name", "x", "y"],
+        };
+        fields.iter().position(|f| *f == field)
+    }
+}
+
+/// Eintrag in `network.osc_mappings`
+///
+/// `address` darf OSC-Wildcards enthalten (`*`, `?`, `[abc]`, `{foo,bar}`),
+/// `args` ordnet Feldern des Events eine Argumentposition zu (z.B. `{"hand_id": 1, "distance": 0}`).
+#[derive(Deserialize, Clone, Debug)]
+pub struct OscMapping {
+    pub address: String,
+    pub event: OscEventKind,
+    #[serde(default)]
+    pub args: HashMap<String, usize>,
+}
+
+impl OscMapping {
+    fn new(address: &str, event: OscEventKind) -> Self {
+        Self {
+            address: address.to_string(),
+            event,
+            args: HashMap::new(),
+        }
+    }
+
+    fn position(&self, field: &str) -> Option<usize> {
+        self.args.get(field).copied().or_else(|| self.event.default_position(field))
+    }
+
+    /// Baut das Event aus den OSC-Argumenten; `None`, wenn ein Argument fehlt oder nicht passt
+    fn build_event(&self, args: &[OscType]) -> Option<GameEvent> {
+        let int = |field: &str| self.position(field).and_then(|i| args.get(i)).and_then(coerce_i32);
+        let float = |field: &str| self.position(field).and_then(|i| args.get(i)).and_then(coerce_f32);
+        let string = |field: &str| self.position(field).and_then(|i| args.get(i)).and_then(coerce_string);
+
+        Some(match self.event {
+            OscEventKind::HandCountChanged => GameEvent::HandCountChanged(int("count")?),
+            OscEventKind::HandGesture => GameEvent::HandGesture {
+                hand_id: int("hand_id")?,
+                gesture: string("gesture")?,
+            },
+            OscEventKind::HandPinchDistance => GameEvent::HandPinchDistance {
+                hand_id: int("hand_id")?,
+                distance: float("distance")?,
+            },
+            OscEventKind::HandPosition => GameEvent::HandPosition {
+                hand_id: int("hand_id")?,
+                x: float("x")?,
+                y: float("y")?,
+            },
+            OscEventKind::ObjectDetected => GameEvent::ObjectDetected {
+                name: string("name")?,
+                x: float("x")?,
+                y: float("y")?,
+            },
+        })
+    }
+}
+
+/// Bisher fest verdrahtete Adressen
+pub fn default_osc_mappings() -> Vec<OscMapping> {
+    vec![
+        OscMapping::new("/hand/count", OscEventKind::HandCountChanged),
+        OscMapping::new("/hand/gesture", OscEventKind::HandGesture),
+        OscMapping::new("/hand/pinch", OscEventKind::HandPinchDistance),
+        OscMapping::new("/hand/position", OscEventKind::HandPosition),
+        OscMapping::new("/object/detected", OscEventKind::ObjectDetected),
+    ]
+}
+
+/// Prüft alle Adress-Muster, gibt Fehlermeldungen zurück
+pub fn validate_osc_mappings(mappings: &[OscMapping]) -> Vec<String> {
+    mappings
+        .iter()
+        .filter_map(|mapping| {
+            Matcher::new(&mapping.address)
+                .err()
+                .map(|e| format!("network.osc_mappings: ungültiges Muster '{}': {}", mapping.address, e))
+        })
+        .collect()
+}
+
+/// Kompilierte Zuordnung OSC-Adresse -> `GameEvent`
+#[derive(Resource, Default)]
+pub struct OscAddressMap {
+    entries: Vec<(Matcher, OscMapping)>,
+    /// Bereits gemeldete unbekannte Adressen; begrenzt, da sie aus dem Netz kommen
+    warned: HashSet<String>,
+}
+
+impl OscAddressMap {
+    pub fn from_mappings(mappings: &[OscMapping]) -> Self {
+        let entries = mappings
+            .iter()
+            .filter_map(|mapping| match Matcher::new(&mapping.address) {
+                Ok(matcher) => Some((matcher, mapping.clone())),
+                Err(e) => {
+                    warn!("OSC-Mapping '{}' ignoriert: {}", mapping.address, e);
+                    None
+                }
+            })
+            .collect();
+        Self { entries, warned: HashSet::new() }
+    }
+
+    /// Alle Events, die aus einer Nachricht entstehen (mehrere Mappings dürfen greifen)
+    pub fn events_for(&mut self, msg: &OscMessage) -> Vec<GameEvent> {
+        let Ok(address) = OscAddress::new(msg.addr.clone()) else {
+            self.warn_once(&msg.addr, "ungültige Adresse");
+            return Vec::new();
+        };
+
+        let mut matched = false;
+        let mut events = Vec::new();
+        for (matcher, mapping) in &self.entries {
+            if !matcher.match_address(&address) {
+                continue;
+            }
+            matched = true;
+            match mapping.build_event(&msg.args) {
+                Some(event) => events.push(event),
+                None => debug!("OSC {} passt nicht zu {:?}: {:?}", msg.addr, mapping.event, msg.args),
+            }
+        }
+
+        if !matched {
+            self.warn_once(&msg.addr, "kein Mapping");
+        }
+        events
+    }
+
+    fn warn_once(&mut self, address: &str, reason: &str) {
+        if self.warned.len() >= MAX_WARNED_ADDRESSES || self.warned.contains(address) {
+            return;
+        }
+        self.warned.insert(address.to_string());
+        warn!("Unbekannte OSC-Adresse {} ({})", address, reason);
+        if self.warned.len() == MAX_WARNED_ADDRESSES {
+            warn!("{} unbekannte OSC-Adressen gemeldet, weitere werden nicht mehr protokolliert", MAX_WARNED_ADDRESSES);
+        }
+    }
+}
+
+fn coerce_i32(arg: &OscType) -> Option<i32> {
+    match arg {
+        OscType::Int(i) => Some(*i),
+        OscType::Long(l) => i32::try_from(*l).ok(),
+        OscType::Float(f) if f.is_finite() => Some(f.round() as i32),
+        OscType::Double(d) if d.is_finite() => Some(d.round() as i32),
+        OscType::String(s) => s.trim().parse().ok(),
+        OscType::Bool(b) => Some(*b as i32),
+        _ => None,
+    }
+}
+
+fn coerce_f32(arg: &OscType) -> Option<f32> {
+    match arg {
+        OscType::Float(f) => Some(*f),
+        OscType::Double(d) => Some(*d as f32),
+        OscType::Int(i) => Some(*i as f32),
+        OscType::Long(l) => Some(*l as f32),
+        OscType::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+    .filter(|value: &f32| value.is_finite())
+}
+
+fn coerce_string(arg: &OscType) -> Option<String> {
+    match arg {
+        OscType::String(s) => Some(s.clone()),
+        OscType::Int(i) => Some(i.to_string()),
+        OscType::Long(l) => Some(l.to_string()),
+        OscType::Float(f) => Some(f.to_string()),
+        OscType::Double(d) => Some(d.to_string()),
+        OscType::Char(c) => Some(c.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(addr: &str, args: Vec<OscType>) -> OscMessage {
+        OscMessage { addr: addr.to_string(), args }
+    }
+
+    fn mapping(address: &str, event: OscEventKind, args: &[(&str, usize)]) -> OscMapping {
+        OscMapping {
+            address: address.to_string(),
+            event,
+            args: args.iter().map(|(field, index)| (field.to_string(), *index)).collect(),
+        }
+    }
+
+    fn debug(events: Vec<GameEvent>) -> Vec<String> {
+        events.iter().map(|event| format!("{:?}", event)).collect()
+    }
+
+    #[test]
+    fn wildcards_match_addresses() {
+        let mut map = OscAddressMap::from_mappings(&[
+            mapping("/{object,objects}/detected", OscEventKind::ObjectDetected, &[]),
+            mapping("/hand/?ount", OscEventKind::HandCountChanged, &[]),
+            mapping("/tracker/*/gesture", OscEventKind::HandGesture, &[]),
+        ]);
+        let object = vec![OscType::String("cup".to_string()), OscType::Float(0.5), OscType::Float(0.25)];
+
+        assert_eq!(map.events_for(&message("/object/detected", object.clone())).len(), 1);
+        assert_eq!(map.events_for(&message("/objects/detected", object.clone())).len(), 1);
+        assert!(map.events_for(&message("/objekt/detected", object)).is_empty());
+        assert_eq!(
+            debug(map.events_for(&message("/hand/count", vec![OscType::Int(2)]))),
+            vec!["HandCountChanged(2)"]
+        );
+        assert_eq!(
+            map.events_for(&message("/tracker/left/gesture", vec![OscType::Int(1), OscType::String("fist".into())]))
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn several_mappings_may_match() {
+        let mut map = OscAddressMap::from_mappings(&[
+            mapping("/hand/*", OscEventKind::HandCountChanged, &[]),
+            mapping("/hand/count", OscEventKind::HandCountChanged, &[]),
+        ]);
+        assert_eq!(map.events_for(&message("/hand/count", vec![OscType::Int(1)])).len(), 2);
+    }
+
+    #[test]
+    fn configured_argument_positions_override_defaults() {
+        let mut map = OscAddressMap::from_mappings(&[mapping(
+            "/hand/pinch",
+            OscEventKind::HandPinchDistance,
+            &[("hand_id", 0), ("distance", 1)],
+        )]);
+        let events = map.events_for(&message("/hand/pinch", vec![OscType::Int(2), OscType::Float(0.5)]));
+        assert_eq!(debug(events), vec!["HandPinchDistance { hand_id: 2, distance: 0.5 }"]);
+
+        let mut defaults = OscAddressMap::from_mappings(&default_osc_mappings());
+        let events = defaults.events_for(&message("/hand/position", vec![
+            OscType::Int(3),
+            OscType::Double(0.25),
+            OscType::String("0.75".to_string()),
+        ]));
+        assert_eq!(debug(events), vec!["HandPosition { hand_id: 3, x: 0.25, y: 0.75 }"]);
+    }
+
+    #[test]
+    fn missing_or_unusable_arguments_produce_no_event() {
+        let mut map = OscAddressMap::from_mappings(&default_osc_mappings());
+        assert!(map.events_for(&message("/hand/pinch", vec![OscType::Int(1)])).is_empty());
+        assert!(map.events_for(&message("/hand/pinch", vec![OscType::Int(1), OscType::Float(f32::NAN)])).is_empty());
+        assert!(map
+            .events_for(&message("/hand/gesture", vec![OscType::Nil, OscType::String("fist".to_string())]))
+            .is_empty());
+    }
+
+    #[test]
+    fn int_coercion() {
+        assert_eq!(coerce_i32(&OscType::Int(4)), Some(4));
+        assert_eq!(coerce_i32(&OscType::Long(5)), Some(5));
+        assert_eq!(coerce_i32(&OscType::Long(i64::MAX)), None);
+        assert_eq!(coerce_i32(&OscType::Float(2.6)), Some(3));
+        assert_eq!(coerce_i32(&OscType::Float(f32::INFINITY)), None);
+        assert_eq!(coerce_i32(&OscType::Double(-1.4)), Some(-1));
+        assert_eq!(coerce_i32(&OscType::String(" 7 ".to_string())), Some(7));
+        assert_eq!(coerce_i32(&OscType::String("sieben".to_string())), None);
+        assert_eq!(coerce_i32(&OscType::Bool(true)), Some(1));
+        assert_eq!(coerce_i32(&OscType::Nil), None);
+    }
+
+    #[test]
+    fn float_and_string_coercion() {
+        assert_eq!(coerce_f32(&OscType::Double(0.5)), Some(0.5));
+        assert_eq!(coerce_f32(&OscType::Int(2)), Some(2.0));
+        assert_eq!(coerce_f32(&OscType::String("0.25".to_string())), Some(0.25));
+        assert_eq!(coerce_f32(&OscType::String("inf".to_string())), None);
+        assert_eq!(coerce_f32(&OscType::Double(f64::NAN)), None);
+        assert_eq!(coerce_f32(&OscType::Bool(true)), None);
+
+        assert_eq!(coerce_string(&OscType::Int(3)), Some("3".to_string()));
+        assert_eq!(coerce_string(&OscType::Char('x')), Some("x".to_string()));
+        assert_eq!(coerce_string(&OscType::Bool(false)), None);
+    }
+
+    #[test]
+    fn unknown_addresses_are_remembered_up_to_a_limit() {
+        let mut map = OscAddressMap::from_mappings(&default_osc_mappings());
+        for index in 0..MAX_WARNED_ADDRESSES * 2 {
+            map.events_for(&message(&format!("/unknown/{}", index), vec![]));
+        }
+        map.events_for(&message("kein/pfad", vec![]));
+        assert_eq!(map.warned.len(), MAX_WARNED_ADDRESSES);
+    }
+
+    #[test]
+    fn invalid_patterns_are_reported() {
+        let problems = validate_osc_mappings(&[
+            mapping("/hand/count", OscEventKind::HandCountChanged, &[]),
+            mapping("/hand/{count", OscEventKind::HandCountChanged, &[]),
+        ]);
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("/hand/{count"));
+    }
+}
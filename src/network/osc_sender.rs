@@ -0,0 +1,321 @@
+use bevy::prelude::*;
+use rosc::{OscBundle, OscMessage, OscPacket, OscTime, OscType};
+use serde::Deserialize;
+use std::net::{SocketAddr, UdpSocket};
+use std::time::SystemTime;
+
+use crate::setup::config::{AppConfig, ConfigChanged};
+
+/// Ein OSC-Trigger: Zieladresse und ob er aktiv ist
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct OscTrigger {
+    pub enabled: bool,
+    pub address: String,
+}
+
+impl OscTrigger {
+    fn new(address: &str) -> Self {
+        Self { enabled: true, address: address.to_string() }
+    }
+}
+
+impl Default for OscTrigger {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+/// Einstellungen für ausgehendes OSC (`network.osc_output`)
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct OscOutputConfig {
+    pub enabled: bool,
+    /// Empfänger, z.B. Lichtpult oder Sound-Engine ("host:port")
+    pub targets: Vec<String>,
+    /// Nachrichten eines Frames als ein Bundle mit Zeitstempel senden
+    pub bundle: bool,
+    pub product_selected: OscTrigger,
+    pub collision: OscTrigger,
+    /// Kollisionen unterhalb dieses Impulses werden nicht gemeldet
+    pub collision_min_impulse: f32,
+    pub state_changed: OscTrigger,
+    pub hand_count_changed: OscTrigger,
+}
+
+impl OscOutputConfig {
+    fn triggers(&self) -> [(&'static str, &OscTrigger); 4] {
+        [
+            ("product_selected", &self.product_selected),
+            ("collision", &self.collision),
+            ("state_changed", &self.state_changed),
+            ("hand_count_changed", &self.hand_count_changed),
+        ]
+    }
+}
+
+/// Prüft die Adressen aller aktiven Trigger; liefert eine Meldung pro Problem
+pub fn validate_osc_triggers(output: &OscOutputConfig) -> Vec<String> {
+    output
+        .triggers()
+        .into_iter()
+        .filter(|(_, trigger)| trigger.enabled && !trigger.address.starts_with('/'))
+        .map(|(name, trigger)| {
+            format!(
+                "network.osc_output.{}: Adresse '{}' muss mit '/' beginnen",
+                name, trigger.address
+            )
+        })
+        .collect()
+}
+
+impl Default for OscOutputConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            targets: vec!["127.0.0.1:9000".to_string()],
+            bundle: true,
+            product_selected: OscTrigger::new("/storefront/product/selected"),
+            collision: OscTrigger::new("/storefront/collision"),
+            collision_min_impulse: 5.0,
+            state_changed: OscTrigger::new("/storefront/state"),
+            hand_count_changed: OscTrigger::new("/storefront/hands/count"),
+        }
+    }
+}
+
+/// Ausgehende OSC-Nachricht; wird am Frame-Ende an alle Ziele verschickt
+#[derive(Message, Clone, Debug)]
+pub struct OscOutgoing {
+    pub address: String,
+    pub args: Vec<OscType>,
+}
+
+/// UDP-Socket und aufgelöste Ziele für ausgehendes OSC
+#[derive(Resource)]
+pub struct OscSender {
+    socket: Option<UdpSocket>,
+    targets: Vec<SocketAddr>,
+}
+
+impl OscSender {
+    pub fn new(targets: &[String]) -> Self {
+        let targets = parse_targets(targets);
+        let socket = UdpSocket::bind("0.0.0.0:0")
+            .and_then(|socket| socket.set_nonblocking(true).map(|_| socket))
+            .map_err(|e| error!("OSC-Sender: Socket konnte nicht geöffnet werden: {}", e))
+            .ok();
+
+        Self { socket, targets }
+    }
+
+    /// Ersetzt die Ziele, der Socket bleibt bestehen
+    pub fn set_targets(&mut self, targets: &[String]) {
+        self.targets = parse_targets(targets);
+    }
+
+    /// Kodiert die Nachrichten (optional als Bundle mit aktuellem Zeitstempel)
+    pub fn encode(messages: Vec<OscMessage>, bundle: bool) -> Vec<Vec<u8>> {
+        let packets = if bundle {
+            let timetag = OscTime::try_from(SystemTime::now()).unwrap_or(OscTime { seconds: 0, fractional: 1 });
+            vec![OscPacket::Bundle(OscBundle {
+                timetag,
+                content: messages.into_iter().map(OscPacket::Message).collect(),
+            })]
+        } else {
+            messages.into_iter().map(OscPacket::Message).collect()
+        };
+
+        packets
+            .iter()
+            .filter_map(|packet| {
+                rosc::encoder::encode(packet)
+                    .map_err(|e| error!("OSC-Paket konnte nicht kodiert werden: {}", e))
+                    .ok()
+            })
+            .collect()
+    }
+
+    pub fn send(&self, packets: &[Vec<u8>]) {
+        let Some(socket) = &self.socket else {
+            return;
+        };
+        for target in &self.targets {
+            for packet in packets {
+                if let Err(e) = socket.send_to(packet, target) {
+                    warn!("OSC an {} fehlgeschlagen: {}", target, e);
+                }
+            }
+        }
+    }
+}
+
+fn parse_targets(targets: &[String]) -> Vec<SocketAddr> {
+    targets
+        .iter()
+        .filter_map(|target| match target.parse() {
+            Ok(addr) => Some(addr),
+            Err(e) => {
+                warn!("OSC-Ziel '{}' ignoriert: {}", target, e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Sendet `OscOutgoing`-Nachrichten per UDP an alle Ziele aus `network.osc_output.targets`.
+/// `targets` sind die Ziele beim Start; Änderungen der settings.json werden per Hot-Reload übernommen.
+///
+/// Zum Testen reicht ein lokaler Empfänger, z.B. `nc -u -l 9000` bzw. ein
+/// `UdpSocket` auf `127.0.0.1:9000` mit `rosc::decoder::decode_udp`.
+pub struct OscSenderPlugin {
+    pub targets: Vec<String>,
+}
+
+impl Plugin for OscSenderPlugin {
+    fn build(&self, app: &mut App) {
+        let sender = OscSender::new(&self.targets);
+        info!("OSC-Sender sendet an {:?}", sender.targets);
+
+        app.insert_resource(sender)
+            .add_message::<OscOutgoing>()
+            .add_systems(Update, reload_osc_targets)
+            .add_systems(PostUpdate, send_osc_messages_system);
+    }
+}
+
+/// Übernimmt geänderte `network.osc_output.targets` nach einer Änderung der settings.json
+fn reload_osc_targets(
+    mut changes: MessageReader<ConfigChanged>,
+    config: Res<AppConfig>,
+    mut sender: ResMut<OscSender>,
+) {
+    let Some(change) = changes.read().last() else {
+        return;
+    };
+    let targets = &config.network.osc_output.targets;
+    if *targets == change.previous.network.osc_output.targets {
+        return;
+    }
+    sender.set_targets(targets);
+    info!("🔄 OSC-Sender sendet jetzt an {:?}", sender.targets);
+}
+
+fn send_osc_messages_system(
+    mut outgoing: MessageReader<OscOutgoing>,
+    sender: Res<OscSender>,
+    config: Res<AppConfig>,
+) {
+    let output = &config.network.osc_output;
+    let messages: Vec<OscMessage> = outgoing
+        .read()
+        .map(|msg| OscMessage { addr: msg.address.clone(), args: msg.args.clone() })
+        .collect();
+    if !output.enabled || messages.is_empty() {
+        return;
+    }
+
+    sender.send(&OscSender::encode(messages, output.bundle));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn receiver() -> (UdpSocket, String) {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        socket.set_read_timeout(Some(Duration::from_secs(2))).unwrap();
+        let addr = socket.local_addr().unwrap().to_string();
+        (socket, addr)
+    }
+
+    fn receive(socket: &UdpSocket) -> OscPacket {
+        let mut buf = [0u8; 65536];
+        let (size, _) = socket.recv_from(&mut buf).unwrap();
+        rosc::decoder::decode_udp(&buf[..size]).unwrap().1
+    }
+
+    fn message(addr: &str, args: Vec<OscType>) -> OscMessage {
+        OscMessage { addr: addr.to_string(), args }
+    }
+
+    #[test]
+    fn sends_single_messages() {
+        let (socket, addr) = receiver();
+        let sender = OscSender::new(&[addr]);
+        let packets = OscSender::encode(
+            vec![message("/storefront/product/selected", vec![OscType::String("cup".to_string())])],
+            false,
+        );
+        sender.send(&packets);
+
+        let OscPacket::Message(received) = receive(&socket) else {
+            panic!("Einzelnachricht erwartet");
+        };
+        assert_eq!(received.addr, "/storefront/product/selected");
+        assert_eq!(received.args, vec![OscType::String("cup".to_string())]);
+    }
+
+    #[test]
+    fn sends_frame_as_bundle() {
+        let (socket, addr) = receiver();
+        let sender = OscSender::new(&[addr]);
+        let packets = OscSender::encode(
+            vec![
+                message("/storefront/hands/count", vec![OscType::Int(2)]),
+                message("/storefront/collision", vec![OscType::Float(7.5)]),
+            ],
+            true,
+        );
+        assert_eq!(packets.len(), 1);
+        sender.send(&packets);
+
+        let OscPacket::Bundle(bundle) = receive(&socket) else {
+            panic!("Bundle erwartet");
+        };
+        let addresses: Vec<&str> = bundle
+            .content
+            .iter()
+            .map(|packet| match packet {
+                OscPacket::Message(msg) => msg.addr.as_str(),
+                OscPacket::Bundle(_) => panic!("verschachteltes Bundle"),
+            })
+            .collect();
+        assert_eq!(addresses, vec!["/storefront/hands/count", "/storefront/collision"]);
+    }
+
+    #[test]
+    fn set_targets_redirects_output() {
+        let (old_socket, old_addr) = receiver();
+        let (new_socket, new_addr) = receiver();
+        let mut sender = OscSender::new(&[old_addr, "kein-ziel".to_string()]);
+        assert_eq!(sender.targets.len(), 1);
+
+        sender.set_targets(&[new_addr]);
+        sender.send(&OscSender::encode(vec![message("/storefront/state", vec![])], false));
+
+        let OscPacket::Message(received) = receive(&new_socket) else {
+            panic!("Einzelnachricht erwartet");
+        };
+        assert_eq!(received.addr, "/storefront/state");
+        old_socket.set_nonblocking(true).unwrap();
+        assert!(old_socket.recv_from(&mut [0u8; 64]).is_err());
+    }
+
+    #[test]
+    fn trigger_addresses_are_validated() {
+        assert!(validate_osc_triggers(&OscOutputConfig::default()).is_empty());
+
+        let mut output = OscOutputConfig::default();
+        output.collision.address = String::new();
+        output.state_changed.address = "storefront/state".to_string();
+        let problems = validate_osc_triggers(&output);
+        assert_eq!(problems.len(), 2);
+        assert!(problems[0].contains("collision"));
+        assert!(problems[1].contains("state_changed"));
+
+        output.collision.enabled = false;
+        assert_eq!(validate_osc_triggers(&output).len(), 1);
+    }
+}
@@ -0,0 +1,458 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use super::events::GameEvent;
+use crate::setup::config::AppConfig;
+
+/// Herkunft eines aufgezeichneten Events
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EventSource {
+    Osc,
+    Ws,
+    Simulator,
+}
+
+/// Eine Zeile der Aufnahme (JSON-Lines)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RecordedEvent {
+    /// Sekunden seit Start der Aufnahme
+    pub t: f32,
+    pub source: EventSource,
+    pub event: GameEvent,
+}
+
+/// Einstellungen für Aufnahme und Wiedergabe (`network.recording`)
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct RecordingConfig {
+    /// Zieldatei für Aufnahmen (F9)
+    pub record_path: String,
+    /// Datei für die Wiedergabe (F10)
+    pub replay_path: String,
+    /// 1.0 = Echtzeit, 4.0 = vierfach beschleunigt
+    pub replay_speed: f32,
+    pub replay_loop: bool,
+    /// Wiedergabe direkt beim Start
+    pub replay_on_startup: bool,
+}
+
+impl Default for RecordingConfig {
+    fn default() -> Self {
+        Self {
+            record_path: "recordings/session.jsonl".to_string(),
+            replay_path: "recordings/session.jsonl".to_string(),
+            replay_speed: 1.0,
+            replay_loop: false,
+            replay_on_startup: false,
+        }
+    }
+}
+
+struct ActiveRecording {
+    writer: BufWriter<File>,
+    started_at: f32,
+    count: usize,
+}
+
+/// Schreibt eingehende `GameEvent`s mit Zeitstempel und Quelle mit
+#[derive(Resource, Default)]
+pub struct EventRecorder {
+    active: Option<ActiveRecording>,
+}
+
+impl EventRecorder {
+    pub fn is_recording(&self) -> bool {
+        self.active.is_some()
+    }
+
+    pub fn start(&mut self, path: &str, now: f32) -> io::Result<()> {
+        if let Some(parent) = Path::new(path).parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let writer = BufWriter::new(File::create(path)?);
+        self.active = Some(ActiveRecording { writer, started_at: now, count: 0 });
+        Ok(())
+    }
+
+    /// Beendet die Aufnahme und gibt die Anzahl der Events zurück
+    pub fn stop(&mut self) -> io::Result<usize> {
+        match self.active.take() {
+            Some(mut recording) => {
+                recording.writer.flush()?;
+                Ok(recording.count)
+            }
+            None => Ok(0),
+        }
+    }
+
+    fn record(&mut self, source: EventSource, event: &GameEvent, now: f32) {
+        let Some(recording) = self.active.as_mut() else {
+            return;
+        };
+        let line = RecordedEvent { t: now - recording.started_at, source, event: event.clone() };
+        let result = serde_json::to_string(&line)
+            .map_err(io::Error::other)
+            .and_then(|json| writeln!(recording.writer, "{}", json));
+        match result {
+            Ok(()) => recording.count += 1,
+            Err(e) => {
+                error!("Aufnahme abgebrochen: {}", e);
+                self.active = None;
+            }
+        }
+    }
+}
+
+/// Gemeinsamer Ausgang der Receiver: schreibt das Event und zeichnet es ggf. auf
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct GameEventSink<'w> {
+    writer: MessageWriter<'w, GameEvent>,
+    recorder: ResMut<'w, EventRecorder>,
+    time: Res<'w, Time>,
+}
+
+impl GameEventSink<'_> {
+    pub fn send(&mut self, source: EventSource, event: GameEvent) {
+        self.recorder.record(source, &event, self.time.elapsed_secs());
+        self.writer.write(event);
+    }
+}
+
+/// Spielt eine Aufnahme über denselben Message-Kanal wieder ab
+#[derive(Resource)]
+pub struct EventReplay {
+    events: Vec<RecordedEvent>,
+    next: usize,
+    elapsed: f32,
+    pub speed: f32,
+    pub looped: bool,
+}
+
+impl EventReplay {
+    pub fn new(mut events: Vec<RecordedEvent>, speed: f32, looped: bool) -> Self {
+        events.sort_by(|a, b| a.t.total_cmp(&b.t));
+        Self { events, next: 0, elapsed: 0.0, speed, looped }
+    }
+
+    /// Liest eine JSON-Lines-Aufnahme; fehlerhafte Zeilen werden übersprungen
+    pub fn load(path: &str, speed: f32, looped: bool) -> io::Result<Self> {
+        let events = parse_recording(BufReader::new(File::open(path)?), path)?;
+        Ok(Self::new(events, speed, looped))
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.events.len()
+    }
+
+    /// Rückt um `delta` Sekunden (Echtzeit) vor und liefert alle fälligen Events
+    pub fn advance(&mut self, delta: f32) -> Vec<GameEvent> {
+        self.elapsed += delta * self.speed.max(0.0);
+        let mut due = Vec::new();
+        while let Some(recorded) = self.events.get(self.next) {
+            if recorded.t > self.elapsed {
+                break;
+            }
+            due.push(recorded.event.clone());
+            self.next += 1;
+        }
+        if self.is_finished() && self.looped && !self.events.is_empty() {
+            self.next = 0;
+            self.elapsed = 0.0;
+        }
+        due
+    }
+}
+
+/// Zeilenweise JSON-Parser für Aufnahmen; `origin` erscheint in Warnungen
+fn parse_recording(reader: impl BufRead, origin: &str) -> io::Result<Vec<RecordedEvent>> {
+    let mut events = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<RecordedEvent>(&line) {
+            Ok(event) => events.push(event),
+            Err(e) => warn!("{}:{} übersprungen: {}", origin, index + 1, e),
+        }
+    }
+    Ok(events)
+}
+
+fn start_replay(commands: &mut Commands, recording: &RecordingConfig) {
+    match EventReplay::load(&recording.replay_path, recording.replay_speed, recording.replay_loop) {
+        Ok(replay) => {
+            info!(
+                "▶️ Wiedergabe von {} ({} Events, {:.1}x)",
+                recording.replay_path,
+                replay.events.len(),
+                replay.speed
+            );
+            commands.insert_resource(replay);
+        }
+        Err(e) => error!("Wiedergabe von {} fehlgeschlagen: {}", recording.replay_path, e),
+    }
+}
+
+/// Aufnahme (F9) und Wiedergabe (F10) von `GameEvent`-Streams
+pub struct EventRecordingPlugin;
+
+impl Plugin for EventRecordingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EventRecorder>()
+            .add_message::<GameEvent>()
+            .add_systems(Startup, start_replay_on_startup)
+            .add_systems(Update, (recording_input, replay_recorded_events).chain());
+    }
+}
+
+fn start_replay_on_startup(mut commands: Commands, config: Res<AppConfig>) {
+    if config.network.recording.replay_on_startup {
+        start_replay(&mut commands, &config.network.recording);
+    }
+}
+
+/// F9: Aufnahme starten/stoppen, F10: Wiedergabe starten/stoppen
+fn recording_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    config: Res<AppConfig>,
+    time: Res<Time>,
+    mut recorder: ResMut<EventRecorder>,
+    replay: Option<Res<EventReplay>>,
+) {
+    let recording = &config.network.recording;
+
+    if keyboard.just_pressed(KeyCode::F9) {
+        if recorder.is_recording() {
+            match recorder.stop() {
+                Ok(count) => info!("⏹️ Aufnahme beendet ({} Events) -> {}", count, recording.record_path),
+                Err(e) => error!("Aufnahme konnte nicht abgeschlossen werden: {}", e),
+            }
+        } else {
+            match recorder.start(&recording.record_path, time.elapsed_secs()) {
+                Ok(()) => info!("⏺️ Aufnahme gestartet -> {}", recording.record_path),
+                Err(e) => error!("Aufnahme nach {} nicht möglich: {}", recording.record_path, e),
+            }
+        }
+    }
+
+    if keyboard.just_pressed(KeyCode::F10) {
+        if replay.is_some() {
+            commands.remove_resource::<EventReplay>();
+            info!("⏹️ Wiedergabe gestoppt");
+        } else {
+            start_replay(&mut commands, recording);
+        }
+    }
+}
+
+fn replay_recorded_events(
+    mut commands: Commands,
+    time: Res<Time>,
+    replay: Option<ResMut<EventReplay>>,
+    mut event_writer: MessageWriter<GameEvent>,
+) {
+    let Some(mut replay) = replay else {
+        return;
+    };
+    for event in replay.advance(time.delta_secs()) {
+        event_writer.write(event);
+    }
+    if replay.is_finished() {
+        info!("⏹️ Wiedergabe beendet");
+        commands.remove_resource::<EventReplay>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    use avian3d::prelude::{ColliderAabb, SpatialQueryPipeline};
+    use bevy::diagnostic::DiagnosticsStore;
+    use bevy::gizmos::GizmoPlugin;
+    use bevy::state::app::StatesPlugin;
+    use bevy::time::TimeUpdateStrategy;
+
+    use crate::gamelogic::hands::HandsState;
+    use crate::gamelogic::{GamelogicPlugin, Selectable, Selection};
+    use crate::network::{OscOutgoing, RemoteCommand, ServerMessage};
+    use crate::setup::appstate::{AppState, InteractionMode};
+    use crate::setup::audio::{AmbienceCommand, CollisionImpact, PlaySound};
+    use crate::setup::catalog::ProductCatalog;
+    use crate::setup::diagnostics::{DiagnosticsOverlayVisible, GameEventStats};
+    use crate::setup::particles::ParticleBurst;
+    use crate::setup::presets::{ActivePreset, ScenePresets, SwitchScenePreset};
+    use crate::setup::snapshot::SceneSnapshot;
+    use crate::setup::stresstest::StressTestConfig;
+
+    /// Minimale Konfiguration, unabhängig von der settings.json des Schaufensters
+    const TEST_CONFIG: &str = r#"{
+        "hot_reload": false,
+        "interaction": {
+            "gesture_bindings": {
+                "point": { "action": "highlight_product" },
+                "fist": { "action": "select_product" }
+            }
+        },
+        "assets": {
+            "audio": { "sounds": {} },
+            "textures": {},
+            "models": {},
+            "environment": { "map": "environment_maps/test.hdr", "required": false }
+        }
+    }"#;
+
+    /// Zeigen, dann greifen: hebt das einzige Produkt hervor und wählt es aus
+    const POINT_AND_GRAB: &str = r#"
+{"t":0.0,"source":"simulator","event":{"HandCountChanged":1}}
+{"t":0.2,"source":"simulator","event":{"HandGesture":{"hand_id":1,"gesture":"point"}}}
+{"t":0.5,"source":"simulator","event":{"HandPinchDistance":{"hand_id":1,"distance":0.12}}}
+{"t":1.2,"source":"simulator","event":{"HandGesture":{"hand_id":1,"gesture":"fist"}}}
+"#;
+
+    fn recorded(t: f32, hand_id: i32) -> RecordedEvent {
+        RecordedEvent { t, source: EventSource::Ws, event: GameEvent::HandCountChanged(hand_id) }
+    }
+
+    fn hand_counts(events: &[GameEvent]) -> Vec<i32> {
+        events
+            .iter()
+            .map(|event| match event {
+                GameEvent::HandCountChanged(count) => *count,
+                other => panic!("unerwartetes Event {:?}", other),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn recorded_event_round_trip() {
+        let events = [
+            GameEvent::HandCountChanged(2),
+            GameEvent::HandGesture { hand_id: 1, gesture: "fist".to_string() },
+            GameEvent::HandPinchDistance { hand_id: 1, distance: 0.25 },
+            GameEvent::HandPosition { hand_id: 2, x: 0.1, y: 0.9 },
+            GameEvent::ObjectDetected { name: "cup".to_string(), x: 0.5, y: 0.5 },
+        ];
+        for (index, event) in events.into_iter().enumerate() {
+            let original = RecordedEvent { t: index as f32 * 0.5, source: EventSource::Osc, event };
+            let json = serde_json::to_string(&original).unwrap();
+            let parsed: RecordedEvent = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed.t, original.t);
+            assert_eq!(parsed.source, original.source);
+            assert_eq!(format!("{:?}", parsed.event), format!("{:?}", original.event));
+        }
+    }
+
+    #[test]
+    fn parse_skips_broken_lines() {
+        let jsonl = "{\"t\":0.1,\"source\":\"ws\",\"event\":{\"HandCountChanged\":1}}\n\nkein json\n";
+        let events = parse_recording(jsonl.as_bytes(), "test").unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].source, EventSource::Ws);
+    }
+
+    #[test]
+    fn advance_returns_due_events_in_time_order() {
+        let mut replay = EventReplay::new(vec![recorded(0.3, 3), recorded(0.1, 1), recorded(0.2, 2)], 1.0, false);
+        assert!(replay.advance(0.05).is_empty());
+        assert_eq!(hand_counts(&replay.advance(0.15)), vec![1, 2]);
+        assert_eq!(hand_counts(&replay.advance(0.1)), vec![3]);
+        assert!(replay.is_finished());
+    }
+
+    #[test]
+    fn advance_respects_speed() {
+        let mut replay = EventReplay::new(vec![recorded(1.0, 1), recorded(2.0, 2)], 4.0, false);
+        assert_eq!(hand_counts(&replay.advance(0.25)), vec![1]);
+        assert_eq!(hand_counts(&replay.advance(0.25)), vec![2]);
+    }
+
+    #[test]
+    fn advance_loops_from_the_start() {
+        let mut replay = EventReplay::new(vec![recorded(0.1, 1), recorded(0.2, 2)], 1.0, true);
+        assert_eq!(hand_counts(&replay.advance(0.2)), vec![1, 2]);
+        assert!(!replay.is_finished());
+        assert_eq!(hand_counts(&replay.advance(0.1)), vec![1]);
+        assert_eq!(hand_counts(&replay.advance(0.1)), vec![2]);
+    }
+
+    /// App mit GamelogicPlugin ohne Fenster, Renderer und Netzwerk; Zeit läuft in festen Schritten
+    fn gamelogic_app(step: Duration) -> App {
+        let config: AppConfig = serde_json::from_str(TEST_CONFIG).expect("Test-Konfiguration gültig");
+        assert!(config.validate().is_empty(), "{:?}", config.validate());
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default(), StatesPlugin, GizmoPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(step))
+            .init_asset::<Image>()
+            .init_asset::<Mesh>()
+            .init_asset::<Gltf>()
+            .init_asset::<bevy::gltf::GltfMesh>()
+            .init_asset::<bevy::text::Font>()
+            .add_message::<GameEvent>()
+            .add_message::<ServerMessage>()
+            .add_message::<RemoteCommand>()
+            .add_message::<OscOutgoing>()
+            .add_message::<PlaySound>()
+            .add_message::<AmbienceCommand>()
+            .add_message::<CollisionImpact>()
+            .add_message::<ParticleBurst>()
+            .add_message::<SwitchScenePreset>()
+            .add_message::<SceneSnapshot>()
+            .insert_resource(config)
+            .insert_resource(ProductCatalog::default())
+            .insert_resource(ScenePresets::default())
+            .init_resource::<ActivePreset>()
+            .init_resource::<StressTestConfig>()
+            .init_resource::<DiagnosticsOverlayVisible>()
+            .init_resource::<GameEventStats>()
+            .init_resource::<DiagnosticsStore>()
+            .init_resource::<SpatialQueryPipeline>()
+            .init_state::<AppState>()
+            .add_sub_state::<InteractionMode>()
+            .add_plugins(GamelogicPlugin);
+        app
+    }
+
+    #[test]
+    fn replay_drives_gamelogic_deterministically() {
+        let step = Duration::from_millis(100);
+        let mut app = gamelogic_app(step);
+        let product = app
+            .world_mut()
+            .spawn((
+                Selectable,
+                Transform::default(),
+                GlobalTransform::default(),
+                ColliderAabb { min: Vec3::splat(-0.5), max: Vec3::splat(0.5) },
+            ))
+            .id();
+
+        let events = parse_recording(POINT_AND_GRAB.as_bytes(), "POINT_AND_GRAB").unwrap();
+        let mut replay = EventReplay::new(events, 1.0, false);
+        while !replay.is_finished() {
+            for event in replay.advance(step.as_secs_f32()) {
+                app.world_mut().write_message(event);
+            }
+            app.update();
+        }
+        app.update();
+
+        let stats = app.world().resource::<GameEventStats>();
+        assert_eq!(stats.hand_count_changed, 1);
+        assert_eq!(stats.hand_gesture, 2);
+        assert_eq!(stats.hand_pinch_distance, 1);
+        assert_eq!(app.world().resource::<HandsState>().reported_count, 1);
+
+        let selection = app.world().resource::<Selection>();
+        assert_eq!(selection.highlighted, Some(product));
+        assert_eq!(selection.selected, Some(product));
+    }
+}
@@ -0,0 +1,240 @@
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use rand::Rng;
+use serde::Deserialize;
+
+use super::events::GameEvent;
+use super::recording::{EventRecorder, EventSource, GameEventSink};
+use crate::setup::config::AppConfig;
+
+/// Betriebsart des Simulators
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SimulatorMode {
+    /// Maus = Hand 0, Mausrad = Pinch, Zifferntasten = Gesten, O = Objekt
+    Mouse,
+    /// Ohne Eingabe: sinusförmiger Pinch und zufällige Gesten (Dauertest)
+    Scripted,
+}
+
+/// Einstellungen für den Tracking-Simulator (`network.simulator`, nur Debug-Builds)
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct SimulatorConfig {
+    pub enabled: bool,
+    pub mode: SimulatorMode,
+    /// Gesten auf den Tasten 1-4 und 6-9 (5 schaltet den FPS-Graphen)
+    pub gestures: Vec<String>,
+    /// Klassenname für simulierte Objekte (Taste O)
+    pub object_class: String,
+    pub pinch_min: f32,
+    pub pinch_max: f32,
+    /// Pinch-Änderung pro Mausrad-Zeile
+    pub pinch_step: f32,
+    /// Anzahl simulierter Hände im Scripted-Modus
+    pub scripted_hands: i32,
+    pub scripted_pinch_period_secs: f32,
+    pub scripted_gesture_interval_secs: f32,
+}
+
+impl Default for SimulatorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mode: SimulatorMode::Mouse,
+            gestures: ["open_palm", "fist", "point", "thumbs_up", "victory"]
+                .iter()
+                .map(|g| g.to_string())
+                .collect(),
+            object_class: "cup".to_string(),
+            pinch_min: 0.02,
+            pinch_max: 0.3,
+            pinch_step: 0.01,
+            scripted_hands: 2,
+            scripted_pinch_period_secs: 4.0,
+            scripted_gesture_interval_secs: 3.0,
+        }
+    }
+}
+
+const GESTURE_KEYS: [KeyCode; 8] = [
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+    KeyCode::Digit9,
+];
+
+/// Zustand des Simulators (F8 schaltet ihn zur Laufzeit an/aus)
+#[derive(Resource, Default)]
+pub struct SimulatorState {
+    pub active: bool,
+    mouse_in_window: bool,
+    pinch: f32,
+    held_gesture: Option<usize>,
+    object_visible: bool,
+    scripted_gestures: Vec<String>,
+    next_gesture_at: f32,
+}
+
+/// Erzeugt `GameEvent`s aus Maus/Tastatur oder einem Skript – für Entwicklung ohne Sensoren.
+/// Achtung: Das Mausrad zoomt weiterhin auch die Orbit-Kamera.
+pub struct TrackingSimulatorPlugin;
+
+impl Plugin for TrackingSimulatorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SimulatorState>()
+            .init_resource::<EventRecorder>()
+            .add_message::<GameEvent>()
+            .add_systems(Startup, init_simulator)
+            .add_systems(Update, (
+                toggle_simulator,
+                simulate_mouse_input.run_if(simulator_mode(SimulatorMode::Mouse)),
+                simulate_scripted_input.run_if(simulator_mode(SimulatorMode::Scripted)),
+            ).chain());
+    }
+}
+
+fn simulator_mode(mode: SimulatorMode) -> impl Fn(Res<SimulatorState>, Res<AppConfig>) -> bool {
+    move |state: Res<SimulatorState>, config: Res<AppConfig>| state.active && config.network.simulator.mode == mode
+}
+
+fn init_simulator(config: Res<AppConfig>, mut state: ResMut<SimulatorState>) {
+    let simulator = &config.network.simulator;
+    state.active = simulator.enabled;
+    state.pinch = (simulator.pinch_min + simulator.pinch_max) * 0.5;
+    if state.active {
+        info!("🧪 Tracking-Simulator aktiv ({:?}), F8 schaltet ihn ab", simulator.mode);
+    }
+}
+
+fn toggle_simulator(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    config: Res<AppConfig>,
+    mut state: ResMut<SimulatorState>,
+    mut event_sink: GameEventSink,
+) {
+    if !keyboard.just_pressed(KeyCode::F8) {
+        return;
+    }
+    state.active = !state.active;
+    info!("🧪 Tracking-Simulator {} ({:?})", if state.active { "an" } else { "aus" }, config.network.simulator.mode);
+    if !state.active {
+        state.mouse_in_window = false;
+        state.scripted_gestures.clear();
+        event_sink.send(EventSource::Simulator, GameEvent::HandCountChanged(0));
+    }
+}
+
+fn simulate_mouse_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut mouse_wheel: MessageReader<MouseWheel>,
+    config: Res<AppConfig>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut state: ResMut<SimulatorState>,
+    mut event_sink: GameEventSink,
+) {
+    let simulator = &config.network.simulator;
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let cursor = window
+        .cursor_position()
+        .map(|position| position / Vec2::new(window.width(), window.height()));
+
+    // Maus im Fenster = eine Hand im Bild
+    if cursor.is_some() != state.mouse_in_window {
+        state.mouse_in_window = cursor.is_some();
+        event_sink.send(EventSource::Simulator, GameEvent::HandCountChanged(cursor.is_some() as i32));
+    }
+    let Some(cursor) = cursor else {
+        mouse_wheel.clear();
+        return;
+    };
+    event_sink.send(EventSource::Simulator, GameEvent::HandPosition { hand_id: 0, x: cursor.x, y: cursor.y });
+
+    let mut scroll = 0.0;
+    for wheel in mouse_wheel.read() {
+        scroll += match wheel.unit {
+            MouseScrollUnit::Line => wheel.y,
+            MouseScrollUnit::Pixel => wheel.y / 40.0,
+        };
+    }
+    if scroll != 0.0 {
+        state.pinch = (state.pinch + scroll * simulator.pinch_step).clamp(simulator.pinch_min, simulator.pinch_max);
+    }
+    event_sink.send(EventSource::Simulator, GameEvent::HandPinchDistance { hand_id: 0, distance: state.pinch });
+
+    // Geste wird gesendet, solange die Taste gehalten wird
+    let held = GESTURE_KEYS
+        .iter()
+        .take(simulator.gestures.len())
+        .position(|key| keyboard.pressed(*key));
+    match held {
+        Some(index) => event_sink.send(EventSource::Simulator, GameEvent::HandGesture {
+            hand_id: 0,
+            gesture: simulator.gestures[index].clone(),
+        }),
+        None if state.held_gesture.is_some() => event_sink.send(EventSource::Simulator, GameEvent::HandGesture {
+            hand_id: 0,
+            gesture: "none".to_string(),
+        }),
+        None => {}
+    }
+    state.held_gesture = held;
+
+    if keyboard.just_pressed(KeyCode::KeyO) {
+        state.object_visible = !state.object_visible;
+        info!("🧪 Simuliertes Objekt '{}' {}", simulator.object_class, if state.object_visible { "an" } else { "aus" });
+    }
+    if state.object_visible {
+        event_sink.send(EventSource::Simulator, GameEvent::ObjectDetected {
+            name: simulator.object_class.clone(),
+            x: cursor.x,
+            y: cursor.y,
+        });
+    }
+}
+
+fn simulate_scripted_input(
+    config: Res<AppConfig>,
+    time: Res<Time>,
+    mut state: ResMut<SimulatorState>,
+    mut event_sink: GameEventSink,
+) {
+    let simulator = &config.network.simulator;
+    let now = time.elapsed_secs();
+    let hands = simulator.scripted_hands.max(0);
+    if state.scripted_gestures.len() != hands as usize {
+        state.scripted_gestures = vec!["none".to_string(); hands as usize];
+        event_sink.send(EventSource::Simulator, GameEvent::HandCountChanged(hands));
+    }
+    let change_gesture = now >= state.next_gesture_at;
+    if change_gesture {
+        state.next_gesture_at = now + simulator.scripted_gesture_interval_secs.max(0.1);
+    }
+
+    let mut rng = rand::rng();
+    let period = simulator.scripted_pinch_period_secs.max(0.1);
+    for hand_id in 0..hands {
+        // Phasenversatz pro Hand, damit nicht alle Hände synchron laufen
+        let phase = now / period * std::f32::consts::TAU + hand_id as f32 * 1.3;
+        let wave = phase.sin() * 0.5 + 0.5;
+        let distance = simulator.pinch_min + (simulator.pinch_max - simulator.pinch_min) * wave;
+        event_sink.send(EventSource::Simulator, GameEvent::HandPinchDistance { hand_id, distance });
+
+        let position = Vec2::new(0.5 + 0.3 * (phase * 0.5).cos(), 0.5 + 0.2 * (phase * 0.7).sin());
+        event_sink.send(EventSource::Simulator, GameEvent::HandPosition { hand_id, x: position.x, y: position.y });
+
+        if change_gesture && !simulator.gestures.is_empty() {
+            let index = rng.random_range(0..simulator.gestures.len());
+            state.scripted_gestures[hand_id as usize] = simulator.gestures[index].clone();
+        }
+        let gesture = state.scripted_gestures[hand_id as usize].clone();
+        event_sink.send(EventSource::Simulator, GameEvent::HandGesture { hand_id, gesture });
+    }
+}
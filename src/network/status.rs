@@ -0,0 +1,247 @@
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use crossbeam_channel::{unbounded, Receiver, Sender};
+use serde::Deserialize;
+use std::fmt;
+use std::io;
+use std::net::AddrParseError;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Fehler beim Starten eines Netzwerk-Listeners
+#[derive(Debug)]
+pub enum NetworkError {
+    InvalidAddress { listener: &'static str, address: String, source: AddrParseError },
+    Bind { listener: &'static str, address: String, attempts: u32, source: io::Error },
+    Runtime { listener: &'static str, source: io::Error },
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetworkError::InvalidAddress { listener, address, source } => {
+                write!(f, "{}: ungültige Adresse '{}': {}", listener, address, source)
+            }
+            NetworkError::Bind { listener, address, attempts, source } => {
+                write!(f, "{}: Bind an {} nach {} Versuchen fehlgeschlagen: {}", listener, address, attempts, source)
+            }
+            NetworkError::Runtime { listener, source } => {
+                write!(f, "{}: Laufzeitfehler: {}", listener, source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for NetworkError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NetworkError::InvalidAddress { source, .. } => Some(source),
+            NetworkError::Bind { source, .. } | NetworkError::Runtime { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Zustand eines Listeners
+#[derive(Debug, Clone, PartialEq)]
+pub enum ListenerState {
+    Starting,
+    Retrying { attempt: u32 },
+    Listening,
+    Failed(String),
+    Stopped,
+}
+
+impl fmt::Display for ListenerState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListenerState::Starting => write!(f, "startet"),
+            ListenerState::Retrying { attempt } => write!(f, "Versuch {}", attempt),
+            ListenerState::Listening => write!(f, "lauscht"),
+            ListenerState::Failed(_) => write!(f, "FEHLER"),
+            ListenerState::Stopped => write!(f, "gestoppt"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ListenerStatus {
+    pub name: &'static str,
+    pub address: String,
+    pub state: ListenerState,
+    pub client_count: u32,
+    /// Zeitpunkt der letzten Nachricht (`Time::elapsed_secs`)
+    pub last_message_at: Option<f32>,
+}
+
+/// Status aller Netzwerk-Listener (für das Diagnose-Overlay)
+#[derive(Resource, Default, Debug)]
+pub struct NetworkStatus {
+    pub listeners: Vec<ListenerStatus>,
+}
+
+impl NetworkStatus {
+    pub fn listener_mut(&mut self, name: &str) -> Option<&mut ListenerStatus> {
+        self.listeners.iter_mut().find(|listener| listener.name == name)
+    }
+
+    /// Markiert den Eingang einer Nachricht
+    pub fn touch(&mut self, name: &str, now: f32) {
+        if let Some(listener) = self.listener_mut(name) {
+            listener.last_message_at = Some(now);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum StatusChange {
+    State(ListenerState),
+    ClientConnected,
+    ClientDisconnected,
+}
+
+#[derive(Debug, Clone)]
+pub struct StatusUpdate {
+    listener: &'static str,
+    change: StatusChange,
+}
+
+/// Meldet Statusänderungen aus einem Listener-Thread an die App
+#[derive(Clone)]
+pub struct StatusReporter {
+    listener: &'static str,
+    sender: Sender<StatusUpdate>,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl StatusReporter {
+    pub fn report(&self, change: StatusChange) {
+        // Ist die App schon beendet, interessiert der Status niemanden mehr
+        let _ = self.sender.send(StatusUpdate { listener: self.listener, change });
+    }
+
+    pub fn fail(&self, error: &NetworkError) {
+        error!("❌ {}", error);
+        self.report(StatusChange::State(ListenerState::Failed(error.to_string())));
+    }
+
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutdown.load(Ordering::Relaxed)
+    }
+}
+
+#[derive(Resource)]
+struct NetworkStatusChannel {
+    sender: Sender<StatusUpdate>,
+    receiver: Receiver<StatusUpdate>,
+}
+
+/// Gemeinsames Flag, mit dem alle Listener-Threads beim Beenden gestoppt werden,
+/// und die Threads selbst, damit sie vor dem Prozessende eingesammelt werden
+#[derive(Resource)]
+pub struct NetworkShutdown {
+    flag: Arc<AtomicBool>,
+    threads: Vec<(&'static str, JoinHandle<()>)>,
+}
+
+/// Registriert einen Listener im `NetworkStatus` und liefert seinen Reporter.
+/// Der gemeinsame Kanal und die Systeme werden beim ersten Aufruf angelegt.
+pub fn register_listener(app: &mut App, name: &'static str, address: &str) -> StatusReporter {
+    if !app.world().contains_resource::<NetworkStatusChannel>() {
+        let (sender, receiver) = unbounded();
+        app.insert_resource(NetworkStatusChannel { sender, receiver })
+            .insert_resource(NetworkShutdown { flag: Arc::new(AtomicBool::new(false)), threads: Vec::new() })
+            .init_resource::<NetworkStatus>()
+            .add_systems(PreUpdate, apply_status_updates)
+            .add_systems(Last, shutdown_network_on_exit);
+    }
+
+    app.world_mut().resource_mut::<NetworkStatus>().listeners.push(ListenerStatus {
+        name,
+        address: address.to_string(),
+        state: ListenerState::Starting,
+        client_count: 0,
+        last_message_at: None,
+    });
+
+    StatusReporter {
+        listener: name,
+        sender: app.world().resource::<NetworkStatusChannel>().sender.clone(),
+        shutdown: app.world().resource::<NetworkShutdown>().flag.clone(),
+    }
+}
+
+/// Merkt sich den Thread eines Listeners; beim Beenden wird er (mit Timeout) abgewartet
+pub fn track_listener_thread(app: &mut App, name: &'static str, handle: JoinHandle<()>) {
+    app.world_mut().resource_mut::<NetworkShutdown>().threads.push((name, handle));
+}
+
+/// Wie oft Listener einen belegten Port erneut versuchen (`network.bind_retry`)
+#[derive(Deserialize, Clone, Copy, Debug)]
+#[serde(default)]
+pub struct BindRetryConfig {
+    pub attempts: u32,
+    pub initial_delay_ms: u64,
+}
+
+impl Default for BindRetryConfig {
+    fn default() -> Self {
+        Self { attempts: 5, initial_delay_ms: 500 }
+    }
+}
+
+impl BindRetryConfig {
+    /// Exponentielles Backoff, gedeckelt bei 10 Sekunden
+    pub fn delay(&self, attempt: u32) -> Duration {
+        Duration::from_millis(self.initial_delay_ms.saturating_mul(1 << attempt.min(5)).min(10_000))
+    }
+}
+
+/// Wie oft Listener-Threads auf das Shutdown-Flag schauen
+pub const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// So lange wartet die App beim Beenden insgesamt auf die Listener-Threads
+const SHUTDOWN_JOIN_TIMEOUT: Duration = Duration::from_secs(2);
+
+fn apply_status_updates(channel: Res<NetworkStatusChannel>, mut status: ResMut<NetworkStatus>) {
+    while let Ok(update) = channel.receiver.try_recv() {
+        let Some(listener) = status.listener_mut(update.listener) else {
+            continue;
+        };
+        match update.change {
+            StatusChange::State(state) => {
+                // Gestoppte Listener haben keine Clients mehr (offene Tasks wurden abgebrochen)
+                if state == ListenerState::Stopped {
+                    listener.client_count = 0;
+                }
+                listener.state = state;
+            }
+            StatusChange::ClientConnected => listener.client_count += 1,
+            StatusChange::ClientDisconnected => listener.client_count = listener.client_count.saturating_sub(1),
+        }
+    }
+}
+
+/// Setzt beim `AppExit` das Shutdown-Flag und wartet, bis alle Listener-Threads beendet sind.
+/// Hängt ein Thread länger als `SHUTDOWN_JOIN_TIMEOUT`, wird er nicht weiter abgewartet.
+fn shutdown_network_on_exit(mut exit_reader: MessageReader<AppExit>, mut shutdown: ResMut<NetworkShutdown>) {
+    if exit_reader.read().next().is_none() || shutdown.flag.swap(true, Ordering::Relaxed) {
+        return;
+    }
+    info!("Netzwerk-Listener werden beendet");
+
+    let deadline = Instant::now() + SHUTDOWN_JOIN_TIMEOUT;
+    for (name, handle) in shutdown.threads.drain(..) {
+        while !handle.is_finished() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+        if !handle.is_finished() {
+            warn!("{}-Listener hat sich nicht rechtzeitig beendet", name);
+            continue;
+        }
+        if handle.join().is_err() {
+            error!("❌ {}-Listener ist abgestürzt", name);
+        }
+    }
+}
@@ -1,74 +1,273 @@
 use bevy::prelude::*;
 use crossbeam_channel::{unbounded, Receiver, Sender};
-use futures_util::StreamExt;
-use serde::Deserialize;
+use futures_util::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
 use std::thread;
 use tokio::net::{TcpListener, TcpStream};
 use tokio::runtime::Runtime;
-use tokio_tungstenite::accept_async;
+use tokio::sync::{broadcast, watch};
+use tokio::task::JoinSet;
+use tokio_tungstenite::{accept_async, WebSocketStream};
 use tokio_tungstenite::tungstenite::Message;
 
+use super::commands::RemoteCommand;
 use super::events::GameEvent;
+use super::recording::{EventRecorder, EventSource, GameEventSink};
+use super::status::{
+    register_listener, track_listener_thread, BindRetryConfig, ListenerState, NetworkError, NetworkStatus,
+    StatusChange, StatusReporter, SHUTDOWN_POLL_INTERVAL,
+};
+
+/// So lange bekommen offene Verbindungen beim Beenden Zeit, ihren Close-Frame zu senden
+const CLOSE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(1);
 
 #[derive(Deserialize, Debug, Clone)]
 struct WsHandData {
     id: i32,
     pinch_distance: f32,
     gesture: String,
+    /// Normalisierte Bildschirmposition (optional)
+    x: Option<f32>,
+    y: Option<f32>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 struct WsObjectData {
-    // z.B. name: String, x: f32, ...
+    name: String,
+    x: f32,
+    y: f32,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 struct WsFrameData {
     hand_count: Option<i32>,
     hands: Vec<WsHandData>,
-    _objects: Vec<WsObjectData>,
+    #[serde(default)]
+    objects: Vec<WsObjectData>,
+}
+
+/// Nachricht vom Storefront an alle verbundenen WebSocket-Clients
+#[derive(Message, Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    StateChanged { state: String },
+    ProductSelected { entity: Option<u64>, name: Option<String>, product_id: Option<String> },
+    Health { fps: f64, frame_time_ms: f64, entity_count: u64, state: String },
+    CommandAck { cmd: String, ok: bool, message: Option<String> },
+}
+
+impl ServerMessage {
+    /// Zustands-Nachrichten werden gemerkt und neuen Clients direkt nach dem Verbinden geschickt
+    fn retained_key(&self) -> Option<&'static str> {
+        match self {
+            ServerMessage::StateChanged { .. } => Some("state_changed"),
+            ServerMessage::ProductSelected { .. } => Some("product_selected"),
+            ServerMessage::Health { .. } => Some("health"),
+            ServerMessage::CommandAck { .. } => None,
+        }
+    }
+}
+
+/// Verteilt serialisierte `ServerMessage`s an alle Verbindungen
+#[derive(Clone)]
+struct WsBroadcaster {
+    sender: broadcast::Sender<String>,
+    retained: Arc<Mutex<HashMap<&'static str, String>>>,
+}
+
+#[derive(Resource)]
+struct WsBroadcastSender(WsBroadcaster);
+
+/// Eingehende WebSocket-Nachricht: Tracking-Frame oder Steuerbefehl
+#[derive(Debug, Clone)]
+enum WsIncoming {
+    Frame(WsFrameData),
+    Command(RemoteCommand),
+    InvalidCommand { cmd: String, error: String },
+}
+
+impl WsIncoming {
+    /// Nachrichten mit Feld `cmd` sind Befehle, alles andere Tracking-Frames
+    fn parse(text: &str) -> Result<WsIncoming, serde_json::Error> {
+        let value: serde_json::Value = serde_json::from_str(text)?;
+        match value.get("cmd") {
+            Some(cmd) => {
+                let cmd = cmd.as_str().unwrap_or_default().to_string();
+                Ok(match serde_json::from_value::<RemoteCommand>(value) {
+                    Ok(command) => WsIncoming::Command(command),
+                    Err(e) => WsIncoming::InvalidCommand { cmd, error: e.to_string() },
+                })
+            }
+            None => serde_json::from_value(value).map(WsIncoming::Frame),
+        }
+    }
 }
 
 pub struct WebSocketReceiverPlugin {
     pub listen_address: String,
+    pub bind_retry: BindRetryConfig,
 }
 
+const WS_LISTENER: &str = "WebSocket";
+
 impl Plugin for WebSocketReceiverPlugin {
     fn build(&self, app: &mut App) {
-        let (tx, rx) = unbounded::<WsFrameData>();
+        let (tx, rx) = unbounded::<WsIncoming>();
+        let (broadcast_tx, _) = broadcast::channel::<String>(256);
+        let broadcaster = WsBroadcaster {
+            sender: broadcast_tx,
+            retained: Arc::new(Mutex::new(HashMap::new())),
+        };
+        let reporter = register_listener(app, WS_LISTENER, &self.listen_address);
 
         app.insert_resource(WsMessageReceiver(rx))
+            .insert_resource(WsBroadcastSender(broadcaster.clone()))
+            .init_resource::<EventRecorder>()
             .add_message::<GameEvent>()
-            .add_systems(Update, process_ws_messages_system);
+            .add_message::<ServerMessage>()
+            .add_message::<RemoteCommand>()
+            .add_systems(Update, (process_ws_messages_system, broadcast_server_messages_system));
 
         info!("Starte WebSocket-Listener-Thread auf {}", self.listen_address);
-        start_ws_listener_thread(self.listen_address.clone(), tx);
+        if let Some(handle) =
+            start_ws_listener_thread(self.listen_address.clone(), self.bind_retry, tx, broadcaster, reporter)
+        {
+            track_listener_thread(app, WS_LISTENER, handle);
+        }
     }
 }
 
 #[derive(Resource)]
-struct WsMessageReceiver(Receiver<WsFrameData>);
+struct WsMessageReceiver(Receiver<WsIncoming>);
 
-fn start_ws_listener_thread(listen_address: String, sender: Sender<WsFrameData>) {
-    thread::spawn(move || {
-        let rt = Runtime::new().expect("Konnte Tokio-Runtime nicht erstellen");
-        rt.block_on(run_ws_server(listen_address, sender));
+fn start_ws_listener_thread(
+    listen_address: String,
+    bind_retry: BindRetryConfig,
+    sender: Sender<WsIncoming>,
+    broadcaster: WsBroadcaster,
+    reporter: StatusReporter,
+) -> Option<thread::JoinHandle<()>> {
+    let thread_reporter = reporter.clone();
+    let spawned = thread::Builder::new().name("ws-listener".to_string()).spawn(move || {
+        let reporter = thread_reporter;
+        let rt = match Runtime::new() {
+            Ok(rt) => rt,
+            Err(source) => {
+                reporter.fail(&NetworkError::Runtime { listener: WS_LISTENER, source });
+                return;
+            }
+        };
+        rt.block_on(run_ws_server(listen_address, bind_retry, sender, broadcaster, reporter.clone()));
+        // Verbindungen sind bereits geschlossen; übrig gebliebene Tasks bricht der Drop ab
+        drop(rt);
+        reporter.report(StatusChange::State(ListenerState::Stopped));
     });
+    spawned
+        .map_err(|source| reporter.fail(&NetworkError::Runtime { listener: WS_LISTENER, source }))
+        .ok()
+}
+
+async fn bind_ws_listener(
+    listen_address: &str,
+    bind_retry: BindRetryConfig,
+    reporter: &StatusReporter,
+) -> Result<TcpListener, NetworkError> {
+    let addr: SocketAddr = listen_address.parse().map_err(|source| NetworkError::InvalidAddress {
+        listener: WS_LISTENER,
+        address: listen_address.to_string(),
+        source,
+    })?;
+
+    let mut attempt = 0;
+    loop {
+        match TcpListener::bind(addr).await {
+            Ok(listener) => return Ok(listener),
+            Err(source) => {
+                attempt += 1;
+                if attempt >= bind_retry.attempts.max(1) || reporter.is_shutting_down() {
+                    return Err(NetworkError::Bind {
+                        listener: WS_LISTENER,
+                        address: listen_address.to_string(),
+                        attempts: attempt,
+                        source,
+                    });
+                }
+                let delay = bind_retry.delay(attempt - 1);
+                warn!("Bind an WS-Port {} fehlgeschlagen ({}), neuer Versuch in {:?}", addr, source, delay);
+                reporter.report(StatusChange::State(ListenerState::Retrying { attempt }));
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
 }
 
-async fn run_ws_server(listen_address: String, sender: Sender<WsFrameData>) {
-    let addr = listen_address;
-    let listener = TcpListener::bind(&addr).await.expect("Bind an WS-Port fehlgeschlagen");
-    info!("WebSocket-Server lauscht auf {}", addr);
+async fn run_ws_server(
+    listen_address: String,
+    bind_retry: BindRetryConfig,
+    sender: Sender<WsIncoming>,
+    broadcaster: WsBroadcaster,
+    reporter: StatusReporter,
+) {
+    let listener = match bind_ws_listener(&listen_address, bind_retry, &reporter).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            reporter.fail(&e);
+            return;
+        }
+    };
+    reporter.report(StatusChange::State(ListenerState::Listening));
+    info!("WebSocket-Server lauscht auf {}", listen_address);
+
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let mut connections = JoinSet::new();
+    while !reporter.is_shutting_down() {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, addr)) => {
+                    connections.spawn(handle_connection(
+                        stream,
+                        addr,
+                        sender.clone(),
+                        broadcaster.clone(),
+                        reporter.clone(),
+                        shutdown_rx.clone(),
+                    ));
+                }
+                Err(e) => {
+                    warn!("WebSocket-Verbindung konnte nicht angenommen werden: {}", e);
+                    tokio::time::sleep(SHUTDOWN_POLL_INTERVAL).await;
+                }
+            },
+            // Beendete Verbindungen einsammeln
+            Some(_) = connections.join_next(), if !connections.is_empty() => {}
+            _ = tokio::time::sleep(SHUTDOWN_POLL_INTERVAL) => {}
+        }
+    }
 
-    while let Ok((stream, _)) = listener.accept().await {
-        let sender_clone = sender.clone();
-        tokio::spawn(handle_connection(stream, sender_clone));
+    // Alle Clients bekommen einen Close-Frame, bevor die Runtime abgebaut wird
+    drop(listener);
+    let _ = shutdown_tx.send(true);
+    let closed = tokio::time::timeout(CLOSE_TIMEOUT, async {
+        while connections.join_next().await.is_some() {}
+    })
+    .await;
+    if closed.is_err() {
+        warn!("{} WebSocket-Verbindungen nicht rechtzeitig geschlossen", connections.len());
     }
+    info!("WebSocket-Server auf {} beendet", listen_address);
 }
 
-async fn handle_connection(stream: TcpStream, sender: Sender<WsFrameData>) {
-    let addr = stream.peer_addr().expect("Client hat keine Adresse");
+async fn handle_connection(
+    stream: TcpStream,
+    addr: SocketAddr,
+    sender: Sender<WsIncoming>,
+    broadcaster: WsBroadcaster,
+    reporter: StatusReporter,
+    shutdown: watch::Receiver<bool>,
+) {
     info!("Neuer WebSocket-Client verbunden: {}", addr);
 
     let ws_stream = match accept_async(stream).await {
@@ -79,14 +278,69 @@ async fn handle_connection(stream: TcpStream, sender: Sender<WsFrameData>) {
         }
     };
 
-    let (_write, mut read) = ws_stream.split();
+    reporter.report(StatusChange::ClientConnected);
+    serve_client(ws_stream, addr, sender, broadcaster, shutdown).await;
+    reporter.report(StatusChange::ClientDisconnected);
+    info!("Verbindung zu {} geschlossen.", addr);
+}
+
+async fn serve_client(
+    ws_stream: WebSocketStream<TcpStream>,
+    addr: SocketAddr,
+    sender: Sender<WsIncoming>,
+    broadcaster: WsBroadcaster,
+    mut shutdown: watch::Receiver<bool>,
+) {
+    let (mut write, mut read) = ws_stream.split();
+
+    // Vor dem Snapshot abonnieren, damit keine Nachricht verloren geht
+    let mut outgoing = broadcaster.sender.subscribe();
+    let snapshot: Vec<String> = broadcaster
+        .retained
+        .lock()
+        .map(|retained| retained.values().cloned().collect())
+        .unwrap_or_default();
+    for json in snapshot {
+        if write.send(Message::Text(json.into())).await.is_err() {
+            return;
+        }
+    }
+
+    loop {
+        let msg = tokio::select! {
+            incoming = read.next() => match incoming {
+                Some(msg) => msg,
+                None => break,
+            },
+            outgoing_msg = outgoing.recv() => {
+                match outgoing_msg {
+                    Ok(json) => {
+                        if let Err(e) = write.send(Message::Text(json.into())).await {
+                            warn!("Senden an WebSocket-Client {} fehlgeschlagen: {}", addr, e);
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("WebSocket-Client {} zu langsam, {} Nachrichten verworfen", addr, skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+                continue;
+            }
+            _ = shutdown.changed() => {
+                info!("Schließe WebSocket-Verbindung zu {} (App wird beendet)", addr);
+                if let Err(e) = write.send(Message::Close(None)).await {
+                    warn!("Close-Frame an {} fehlgeschlagen: {}", addr, e);
+                }
+                break;
+            }
+        };
 
-    while let Some(msg) = read.next().await {
         match msg {
             Ok(Message::Text(text)) => {
-                match serde_json::from_str::<WsFrameData>(&text) {
-                    Ok(frame_data) => {
-                        if sender.send(frame_data).is_err() {
+                match WsIncoming::parse(&text) {
+                    Ok(incoming) => {
+                        if sender.send(incoming).is_err() {
                             break;
                         }
                     }
@@ -106,17 +360,34 @@ async fn handle_connection(stream: TcpStream, sender: Sender<WsFrameData>) {
             _ => { /* Ignoriere Binary, Ping, Pong etc. */ }
         }
     }
-    info!("Verbindung zu {} geschlossen.", addr);
 }
 
 fn process_ws_messages_system(
     receiver: Res<WsMessageReceiver>,
-    mut event_writer: MessageWriter<GameEvent>,
+    time: Res<Time>,
+    mut status: ResMut<NetworkStatus>,
+    mut event_sink: GameEventSink,
+    mut command_writer: MessageWriter<RemoteCommand>,
+    mut server_writer: MessageWriter<ServerMessage>,
 ) {
-    while let Ok(frame) = receiver.0.try_recv() {
+    while let Ok(incoming) = receiver.0.try_recv() {
+        status.touch(WS_LISTENER, time.elapsed_secs());
+        let frame = match incoming {
+            WsIncoming::Frame(frame) => frame,
+            WsIncoming::Command(command) => {
+                command_writer.write(command);
+                continue;
+            }
+            WsIncoming::InvalidCommand { cmd, error } => {
+                warn!("Ungültiger WS-Befehl '{}': {}", cmd, error);
+                server_writer.write(ServerMessage::CommandAck { cmd, ok: false, message: Some(error) });
+                continue;
+            }
+        };
+
         if let Some(count) = frame.hand_count {
             if count >= 0 {
-                event_writer.write(GameEvent::HandCountChanged(count));
+                event_sink.send(EventSource::Ws, GameEvent::HandCountChanged(count));
                 trace!("Hand count changed: {}", count);
             } else {
                 warn!("Ungültige hand_count empfangen: {}", count);
@@ -130,7 +401,7 @@ fn process_ws_messages_system(
             }
 
             if hand.pinch_distance.is_finite() {
-                event_writer.write(GameEvent::HandPinchDistance {
+                event_sink.send(EventSource::Ws, GameEvent::HandPinchDistance {
                     hand_id: hand.id,
                     distance: hand.pinch_distance,
                 });
@@ -138,8 +409,14 @@ fn process_ws_messages_system(
                 warn!("Ungültige pinch_distance für Hand {}: {}", hand.id, hand.pinch_distance);
             }
 
+            if let (Some(x), Some(y)) = (hand.x, hand.y) {
+                if x.is_finite() && y.is_finite() {
+                    event_sink.send(EventSource::Ws, GameEvent::HandPosition { hand_id: hand.id, x, y });
+                }
+            }
+
             if !hand.gesture.is_empty() {
-                event_writer.write(GameEvent::HandGesture {
+                event_sink.send(EventSource::Ws, GameEvent::HandGesture {
                     hand_id: hand.id,
                     gesture: hand.gesture,
                 });
@@ -147,5 +424,40 @@ fn process_ws_messages_system(
                 warn!("Leeres gesture für Hand {}", hand.id);
             }
         }
+
+        for object in frame.objects {
+            if object.name.is_empty() || !object.x.is_finite() || !object.y.is_finite() {
+                warn!("Ungültiges Objekt empfangen: {:?}", object);
+                continue;
+            }
+            event_sink.send(EventSource::Ws, GameEvent::ObjectDetected {
+                name: object.name,
+                x: object.x,
+                y: object.y,
+            });
+        }
+    }
+}
+/// Serialisiert `ServerMessage`s und verteilt sie an alle WebSocket-Clients
+fn broadcast_server_messages_system(
+    mut message_reader: MessageReader<ServerMessage>,
+    broadcast_sender: Res<WsBroadcastSender>,
+) {
+    let broadcaster = &broadcast_sender.0;
+    for message in message_reader.read() {
+        let json = match serde_json::to_string(message) {
+            Ok(json) => json,
+            Err(e) => {
+                error!("Konnte ServerMessage nicht serialisieren: {}", e);
+                continue;
+            }
+        };
+        if let Some(key) = message.retained_key() {
+            if let Ok(mut retained) = broadcaster.retained.lock() {
+                retained.insert(key, json.clone());
+            }
+        }
+        // Fehler heißt nur: aktuell kein Client verbunden
+        let _ = broadcaster.sender.send(json);
     }
-}
\ No newline at end of file
+}
@@ -2,18 +2,44 @@ pub mod world;
 pub mod lighting;
 pub mod orbiting_camera;
 pub mod assetloader;
+pub mod asset_registry;
 pub mod appstate;
 pub mod diagnostics;
 pub mod stresstest;
 pub mod gltf_spawner;
+pub mod config;
+pub mod audio;
+pub mod catalog;
 mod loading;
-mod particles;
+pub mod particles;
+pub mod presets;
+pub mod playlist;
+pub mod snapshot;
 mod primitive_spawner;
 
 use bevy::prelude::*;
 use appstate::AppState;
 
 pub fn register_startup_systems(app: &mut App) {
+    if let Some(target_fps) = app.world().get_resource::<config::AppConfig>().map(|c| c.window.fps as f32) {
+        app.insert_resource(diagnostics::FpsGraphConfig { target_fps, ..default() });
+    }
+    app.init_asset::<config::AppConfig>()
+        .init_asset_loader::<config::AppConfigLoader>()
+        .add_message::<config::ConfigChanged>()
+        .add_message::<audio::PlaySound>()
+        .add_message::<audio::AmbienceCommand>()
+        .add_message::<audio::CollisionImpact>()
+        .add_message::<particles::ParticleBurst>()
+        .add_message::<presets::SwitchScenePreset>()
+        .add_message::<snapshot::SceneSnapshot>()
+        .register_type::<world::RadialGravity>()
+        .register_type::<catalog::Product>();
+    app.init_resource::<presets::ActivePreset>();
+    app.init_resource::<audio::AmbienceTrack>();
+    app.init_resource::<playlist::ShopClock>();
+    app.init_resource::<playlist::PlaylistState>();
+    app.init_resource::<assetloader::ReportedOptionalAssets>();
     app.init_resource::<stresstest::StressTestConfig>();
     app.init_resource::<diagnostics::LowestFps>();
     app.init_resource::<diagnostics::AverageFps>();
@@ -26,6 +52,8 @@ pub fn register_startup_systems(app: &mut App) {
         orbiting_camera::spawn_dynamic_orbit_camera,
         loading::spawn_loading_screen,
         assetloader::load_assets_startup,
+        audio::setup_sound_manager,
+        config::watch_config_file,
         lighting::spawn_directional_light,
         lighting::spawn_ambient_light,
         particles::setup,
@@ -44,6 +72,17 @@ pub fn register_update_systems(app: &mut App) {
                 orbiting_camera::sync_dof_focus,
             ).chain(),
 
+            (
+                config::detect_config_changes,
+                assetloader::reload_assets_on_config_change,
+                (
+                    lighting::apply_lighting_config,
+                    orbiting_camera::apply_camera_config,
+                    world::apply_physics_config,
+                    particles::apply_particle_texture,
+                ),
+            ).chain(),
+
             diagnostics::update_fps_text,
             diagnostics::update_average_fps_text,
             diagnostics::update_lowest_fps_text,
@@ -58,56 +97,103 @@ pub fn register_update_systems(app: &mut App) {
             stresstest::stress_test_input,
             stresstest::update_stress_test_info,
             gltf_spawner::toggle_physics_debug,
-            world::spawn_ambience_when_ready,
+            audio::spawn_ambience_when_ready,
             world::apply_radial_gravity.run_if(in_state(AppState::Running)),
-            check_assets_loaded_transition.run_if(in_state(AppState::Loading)),
+            assetloader::check_assets_loaded_transition.run_if(in_state(AppState::Loading)),
             stresstest::spawn_stress_test_objects
                 .run_if(in_state(AppState::Running))
-                .run_if(resource_exists::<assetloader::LoadedModels>)
+                .run_if(resource_exists::<asset_registry::AssetRegistry>)
 
     ));
 
+    app.add_systems(
+        Update,
+        (
+            audio::reload_sound_manager.after(config::detect_config_changes),
+            audio::audio_input,
+            audio::play_sound_requests,
+            audio::handle_ambience_commands,
+            audio::update_sound_fades,
+            audio::apply_sound_volumes,
+        ).chain(),
+    );
+
+    // Nach reload_sound_manager, damit der Audio-Fallback nicht überschrieben wird
+    app.add_systems(
+        Update,
+        assetloader::check_reloaded_assets
+            .after(assetloader::reload_assets_on_config_change)
+            .after(audio::reload_sound_manager)
+            .run_if(in_state(AppState::Running)),
+    );
+
+    app.add_systems(
+        Update,
+        (audio::detect_collision_impacts, audio::play_collision_sounds)
+            .chain()
+            .run_if(in_state(AppState::Running)),
+    );
+
+    app.add_systems(
+        Update,
+        (
+            playlist::advance_shop_clock.after(config::detect_config_changes),
+            playlist::run_playlist,
+            presets::start_preset_transition,
+            presets::run_preset_transition,
+        )
+            .chain()
+            .run_if(in_state(AppState::Running)),
+    );
+
+    app.add_systems(
+        Update,
+        presets::reapply_active_preset
+            .after(lighting::apply_lighting_config)
+            .after(orbiting_camera::apply_camera_config)
+            .run_if(in_state(AppState::Running)),
+    );
+
+    app.add_systems(
+        Update,
+        (snapshot::snapshot_input, snapshot::save_scene_snapshot, snapshot::load_scene_snapshot)
+            .chain()
+            .after(presets::run_preset_transition)
+            .run_if(in_state(AppState::Running)),
+    );
+
+    app.add_systems(Update, (diagnostics::update_network_status_text, diagnostics::update_hands_text));
+
+    app.add_systems(
+        Update,
+        (particles::spawn_particle_bursts, particles::despawn_finished_bursts)
+            .run_if(resource_exists::<asset_registry::AssetRegistry>),
+    );
+
     app.add_systems(
         OnEnter(AppState::Running),
         (
             loading::despawn_loading_screen,
             lighting::spawn_environment_map_light,
-            world::spawn_initial_objects.run_if(resource_exists::<assetloader::LoadedModels>),
+            world::spawn_initial_objects.run_if(resource_exists::<asset_registry::AssetRegistry>),
+            presets::apply_initial_preset,
+            snapshot::load_snapshot_on_start,
             setup_complete_log,
         ).chain()
     );
+
+    app.add_systems(OnEnter(AppState::Loading), assetloader::reset_reported_optional_assets);
+
+    app.add_systems(
+        OnEnter(AppState::LoadError),
+        (loading::despawn_loading_screen, loading::spawn_load_error_screen).chain(),
+    );
+    app.add_systems(
+        OnExit(AppState::LoadError),
+        (loading::despawn_load_error_screen, loading::spawn_loading_screen).chain(),
+    );
 }
 
 fn setup_complete_log() {
     info!("🚀 Setup complete - simulation starting!");
 }
-
-fn check_assets_loaded_transition(
-    asset_handles: Option<Res<crate::setup::assetloader::AssetHandles>>,
-    asset_server: Res<AssetServer>,
-    mut next_state: ResMut<NextState<AppState>>,
-) {
-    if let Some(handles_res) = asset_handles {
-        let handles = &handles_res.0;
-        if handles.is_empty() {
-            info!("No assets to load, transitioning to Running");
-            next_state.set(AppState::Running);
-            return;
-        }
-
-        let all_loaded = handles.iter().all(|handle| {
-            matches!(
-                asset_server.get_load_state(handle.id()),
-                Some(bevy::asset::LoadState::Loaded)
-            )
-        });
-
-        if all_loaded {
-            info!("✅ All assets loaded successfully! Transitioning to Running state");
-            next_state.set(AppState::Running);
-        }
-    } else {
-        info!("No asset handles found, transitioning to Running");
-        next_state.set(AppState::Running);
-    }
-}
@@ -5,5 +5,17 @@ pub enum AppState {
     #[default]
     Loading,
     Running,
+    /// Mindestens ein Pflicht-Asset konnte nicht geladen werden
+    LoadError,
 }
 
+/// Bedienmodus innerhalb von `AppState::Running`
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, SubStates)]
+#[source(AppState = AppState::Running)]
+pub enum InteractionMode {
+    /// Besucher interagieren per Hand-Tracking
+    #[default]
+    Interactive,
+    /// Niemand vor dem Fenster: Kamera-Tour und Partikel-Show
+    Attract,
+}
@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::setup::assetloader::AssetsConfig;
+
+/// Suffix, über den Collider-Modelle ihrem visuellen Modell zugeordnet werden
+pub const COLLIDER_SUFFIX: &str = "_collider";
+
+/// Ein visuelles Modell mit optionalem Collider-Modell (`<name>_collider`)
+#[derive(Clone, Debug)]
+pub struct RegisteredModel {
+    pub visual: Handle<Gltf>,
+    pub collider: Option<Handle<Gltf>>,
+}
+
+impl RegisteredModel {
+    /// Collider-Modell, falls vorhanden – sonst das visuelle Modell selbst
+    pub fn collider_or_visual(&self) -> Handle<Gltf> {
+        self.collider.clone().unwrap_or_else(|| self.visual.clone())
+    }
+}
+
+/// Fehler für einen Schlüssel, der nicht in settings.json registriert ist
+#[derive(Clone, Debug)]
+pub struct MissingAssetKey {
+    pub kind: &'static str,
+    pub key: String,
+    pub available: Vec<String>,
+}
+
+impl fmt::Display for MissingAssetKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} '{}' ist nicht in settings.json registriert (verfügbar: {})",
+            self.kind,
+            self.key,
+            if self.available.is_empty() { "keine".to_string() } else { self.available.join(", ") }
+        )
+    }
+}
+
+impl std::error::Error for MissingAssetKey {}
+
+/// Namensbasierte Registry aller Modelle und Texturen aus den `models`/`textures`-Maps.
+/// Neue Produkte brauchen nur einen Eintrag in settings.json, keine Code-Änderung.
+#[derive(Resource, Default)]
+pub struct AssetRegistry {
+    models: HashMap<String, RegisteredModel>,
+    textures: HashMap<String, Handle<Image>>,
+}
+
+impl AssetRegistry {
+    pub fn from_config(assets: &AssetsConfig, asset_server: &AssetServer) -> Self {
+        let model_paths = &assets.models.models;
+        let mut models = HashMap::new();
+
+        for (name, entry) in model_paths {
+            if let Some(base) = name.strip_suffix(COLLIDER_SUFFIX) {
+                if !model_paths.contains_key(base) {
+                    warn!("Collider-Modell '{}' hat kein visuelles Modell '{}'", name, base);
+                }
+                continue;
+            }
+
+            let collider = model_paths
+                .get(&format!("{}{}", name, COLLIDER_SUFFIX))
+                .map(|collider_entry| asset_server.load(collider_entry.path().to_string()));
+
+            models.insert(
+                name.clone(),
+                RegisteredModel {
+                    visual: asset_server.load(entry.path().to_string()),
+                    collider,
+                },
+            );
+        }
+
+        let textures = assets
+            .textures
+            .iter()
+            .map(|(name, entry)| (name.clone(), asset_server.load(entry.path().to_string())))
+            .collect();
+
+        Self { models, textures }
+    }
+
+    pub fn model(&self, key: &str) -> Result<&RegisteredModel, MissingAssetKey> {
+        self.models.get(key).ok_or_else(|| MissingAssetKey {
+            kind: "Modell",
+            key: key.to_string(),
+            available: sorted_keys(&self.models),
+        })
+    }
+
+    pub fn texture(&self, key: &str) -> Result<&Handle<Image>, MissingAssetKey> {
+        self.textures.get(key).ok_or_else(|| MissingAssetKey {
+            kind: "Textur",
+            key: key.to_string(),
+            available: sorted_keys(&self.textures),
+        })
+    }
+
+    /// Entfernt ein Modell (z.B. nach einem Ladefehler eines optionalen Assets).
+    /// Für `<name>_collider` wird nur der Collider entfernt, das visuelle Modell bleibt.
+    pub fn remove_model(&mut self, key: &str) {
+        if let Some(base) = key.strip_suffix(COLLIDER_SUFFIX) {
+            if let Some(model) = self.models.get_mut(base) {
+                model.collider = None;
+            }
+        } else {
+            self.models.remove(key);
+        }
+    }
+
+    pub fn remove_texture(&mut self, key: &str) {
+        self.textures.remove(key);
+    }
+
+    pub fn model_keys(&self) -> Vec<String> {
+        sorted_keys(&self.models)
+    }
+}
+
+fn sorted_keys<T>(map: &HashMap<String, T>) -> Vec<String> {
+    let mut keys: Vec<String> = map.keys().cloned().collect();
+    keys.sort();
+    keys
+}
@@ -3,172 +3,469 @@ use bevy::audio::AudioSource;
 use bevy::prelude::*;
 use serde::Deserialize;
 use std::collections::HashMap;
-use std::fs;
+use crate::setup::appstate::AppState;
+use crate::setup::asset_registry::AssetRegistry;
+use crate::setup::audio::{CollisionSoundConfig, SoundManager};
+use crate::setup::config::{AppConfig, ConfigChanged};
 
-#[derive(Deserialize)]
-pub struct AssetSettings {
-    pub assets: AssetsConfig,
-}
-
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone, Debug)]
 pub struct AssetsConfig {
     pub audio: AudioConfig,
-    pub textures: HashMap<String, String>, // <- hinzufügen
+    pub textures: HashMap<String, AssetEntry>, // <- hinzufügen
     pub models: ModelsConfig,
     pub environment: EnvironmentConfig,
 }
 
-#[derive(Deserialize, Clone)]
+fn default_required() -> bool {
+    true
+}
+
+/// Asset-Eintrag in settings.json: entweder nur ein Pfad (Pflicht-Asset)
+/// oder `{ "path": "...", "required": false }` für optionale Assets
+#[derive(Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum AssetEntry {
+    Path(String),
+    Detailed {
+        path: String,
+        #[serde(default = "default_required")]
+        required: bool,
+    },
+}
+
+impl AssetEntry {
+    pub fn path(&self) -> &str {
+        match self {
+            AssetEntry::Path(path) => path,
+            AssetEntry::Detailed { path, .. } => path,
+        }
+    }
+
+    pub fn required(&self) -> bool {
+        match self {
+            AssetEntry::Path(_) => true,
+            AssetEntry::Detailed { required, .. } => *required,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
 pub struct AudioEntry {
     pub path: String,
+    #[serde(default = "default_required")]
+    pub required: bool,
     #[serde(default)]
-    pub _looped: bool,
+    pub looped: bool,
     #[serde(default)]
-    pub _volume: Option<f32>,
+    pub volume: Option<f32>,
+    /// Streaming-Sounds blockieren den Ladescreen nicht und starten, sobald sie geladen sind
     #[serde(default)]
-    pub _streaming: bool,
+    pub streaming: bool,
+}
+
+fn default_master_volume() -> f32 {
+    1.0
+}
+
+fn default_fade_seconds() -> f32 {
+    2.0
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone, Debug)]
 pub struct AudioConfig {
+    /// Master-Lautstärke, skaliert alle Sounds
+    #[serde(default = "default_master_volume")]
+    pub volume: f32,
+    /// Dauer für Fade-In/Fade-Out der Ambience
+    #[serde(default = "default_fade_seconds")]
+    pub fade_seconds: f32,
+    #[serde(default)]
+    pub collisions: CollisionSoundConfig,
     pub sounds: HashMap<String, AudioEntry>,
     #[serde(default)]
     pub music: HashMap<String, AudioEntry>, // <- optional machen
 }
 
-#[derive(Resource, Clone)]
-pub struct AmbienceAudio(pub Option<Handle<AudioSource>>);
-
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone, Debug)]
 pub struct ModelsConfig {
     #[serde(flatten)]
-    pub models: HashMap<String, String>,
+    pub models: HashMap<String, AssetEntry>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone, Debug)]
 pub struct EnvironmentConfig {
     pub map: String,
+    #[serde(default = "default_required")]
+    pub required: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrackedAssetKind {
+    Audio,
+    Texture,
+    Model,
+    EnvironmentMap,
+}
+
+/// Ein angefordertes Asset inkl. Herkunft, damit Ladefehler zugeordnet werden können
+#[derive(Clone, Debug)]
+pub struct TrackedAsset {
+    pub kind: TrackedAssetKind,
+    pub name: String,
+    pub path: String,
+    pub required: bool,
+    pub handle: UntypedHandle,
 }
 
 #[derive(Resource, Default)]
-pub struct AssetHandles(pub Vec<UntypedHandle>);
+pub struct AssetHandles(pub Vec<TrackedAsset>);
 
+/// Pflicht-Assets, die nicht geladen werden konnten (Anzeige im LoadError-Screen)
+#[derive(Resource, Default, Clone)]
+pub struct AssetLoadFailures(pub Vec<FailedAsset>);
+
+/// Optionale Assets, deren Fehlschlag im aktuellen Ladedurchgang schon gemeldet wurde
 #[derive(Resource, Default)]
-pub struct LoadedTextures {
-    pub grid_texture: Option<Handle<Image>>,
-    pub dust_particle: Option<Handle<Image>>,
+pub struct ReportedOptionalAssets(Vec<String>);
+
+/// Jeder Ladedurchgang meldet fehlgeschlagene optionale Assets erneut
+pub fn reset_reported_optional_assets(mut reported: ResMut<ReportedOptionalAssets>) {
+    reported.0.clear();
 }
 
-#[derive(Resource, Default)]
-pub struct LoadedModels {
-    pub tasse: Option<Handle<Gltf>>,
-    pub tasse_collider: Option<Handle<Gltf>>,
-    pub plant: Option<Handle<Gltf>>,
-    pub plant_collider: Option<Handle<Gltf>>,
+#[derive(Clone, Debug)]
+pub struct FailedAsset {
+    pub path: String,
+    pub reason: String,
 }
 
 #[derive(Resource, Clone)]
 pub struct LoadedAssetSettings {
-    pub environment_map_path: String,
+    /// `None`, wenn die (optionale) Environment-Map nicht geladen werden konnte
+    pub environment_map_path: Option<String>,
 }
 
-pub fn load_assets(settings: &AssetSettings, asset_server: &AssetServer) -> Vec<UntypedHandle> {
+pub fn load_assets(assets: &AssetsConfig, asset_server: &AssetServer) -> Vec<TrackedAsset> {
     let mut handles = Vec::new();
 
-    for entry in settings.assets.audio.sounds.values() {
+    for (name, entry) in assets.audio.sounds.iter().filter(|(_, e)| !e.streaming) {
         info!("Loading audio asset: {}", entry.path);
         let handle: Handle<AudioSource> = asset_server.load(entry.path.clone());
-        handles.push(handle.untyped());
+        handles.push(TrackedAsset {
+            kind: TrackedAssetKind::Audio,
+            name: name.clone(),
+            path: entry.path.clone(),
+            required: entry.required,
+            handle: handle.untyped(),
+        });
     }
 
-    for entry in settings.assets.audio.music.values() {
+    for (name, entry) in assets.audio.music.iter().filter(|(_, e)| !e.streaming) {
         info!("Loading music asset: {}", entry.path);
         let handle: Handle<AudioSource> = asset_server.load(entry.path.clone());
-        handles.push(handle.untyped());
+        handles.push(TrackedAsset {
+            kind: TrackedAssetKind::Audio,
+            name: name.clone(),
+            path: entry.path.clone(),
+            required: entry.required,
+            handle: handle.untyped(),
+        });
     }
 
-    for (name, path) in &settings.assets.textures {
-        info!("Loading texture '{}': {}", name, path);
-        let handle: Handle<Image> = asset_server.load(path.clone());
-        handles.push(handle.untyped());
+    for (name, entry) in &assets.textures {
+        info!("Loading texture '{}': {}", name, entry.path());
+        let handle: Handle<Image> = asset_server.load(entry.path().to_string());
+        handles.push(TrackedAsset {
+            kind: TrackedAssetKind::Texture,
+            name: name.clone(),
+            path: entry.path().to_string(),
+            required: entry.required(),
+            handle: handle.untyped(),
+        });
     }
 
-    for (name, path) in &settings.assets.models.models {
-        info!("Loading model '{}': {}", name, path);
-        let handle: Handle<Gltf> = asset_server.load(path.clone());
-        handles.push(handle.untyped());
+    for (name, entry) in &assets.models.models {
+        info!("Loading model '{}': {}", name, entry.path());
+        let handle: Handle<Gltf> = asset_server.load(entry.path().to_string());
+        handles.push(TrackedAsset {
+            kind: TrackedAssetKind::Model,
+            name: name.clone(),
+            path: entry.path().to_string(),
+            required: entry.required(),
+            handle: handle.untyped(),
+        });
     }
 
-    let env_map_path = &settings.assets.environment.map;
+    let env_map_path = &assets.environment.map;
     info!("Loading environment map: {}", env_map_path);
     let handle: Handle<Image> = asset_server.load(env_map_path.clone());
-    handles.push(handle.untyped());
+    handles.push(TrackedAsset {
+        kind: TrackedAssetKind::EnvironmentMap,
+        name: "environment".to_string(),
+        path: env_map_path.clone(),
+        required: assets.environment.required,
+        handle: handle.untyped(),
+    });
 
     handles
 }
 
-pub fn load_assets_startup(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let config_path = "assets/config/settings.json";
-    match fs::read_to_string(config_path) {
-        Ok(contents) => match serde_json::from_str::<AssetSettings>(&contents) {
-            Ok(settings) => {
-                let handles = load_assets(&settings, &asset_server);
-                info!("Requested {} assets to load", handles.len());
-                commands.insert_resource(AssetHandles(handles));
+/// Registry, Settings und Sounds, in denen fehlgeschlagene optionale Assets ersetzt werden
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct AssetFallbacks<'w> {
+    registry: Option<ResMut<'w, AssetRegistry>>,
+    loaded_settings: Option<ResMut<'w, LoadedAssetSettings>>,
+    sound_manager: Option<ResMut<'w, SoundManager>>,
+    reported: ResMut<'w, ReportedOptionalAssets>,
+}
 
-                let mut loaded_textures = LoadedTextures::default();
-                if let Some(path) = settings.assets.textures.get("grid_texture") {
-                    loaded_textures.grid_texture = Some(asset_server.load(path.clone()));
-                }
-                if let Some(path) = settings.assets.textures.get("dust_particle") {
-                    loaded_textures.dust_particle = Some(asset_server.load(path.clone()));
-                }
-                commands.insert_resource(loaded_textures);
+impl AssetFallbacks<'_> {
+    fn check(&mut self, handles: &[TrackedAsset], asset_server: &AssetServer) -> LoadProgress {
+        check_load_states(
+            handles,
+            asset_server,
+            &mut self.reported,
+            self.registry.as_deref_mut(),
+            self.loaded_settings.as_deref_mut(),
+            self.sound_manager.as_deref_mut(),
+        )
+    }
+}
 
-                // Load and store typed model handles for easy access
-                let mut loaded_models = LoadedModels::default();
+/// Ergebnis eines Durchgangs über alle angeforderten Assets
+struct LoadProgress {
+    /// Kein Asset lädt mehr
+    all_done: bool,
+    /// Fehlgeschlagene Pflicht-Assets
+    failures: Vec<FailedAsset>,
+}
 
-                if let Some(tasse_path) = settings.assets.models.models.get("tasse") {
-                    loaded_models.tasse = Some(asset_server.load(tasse_path.clone()));
-                }
-                if let Some(tasse_collider_path) = settings.assets.models.models.get("tasse_collider") {
-                    loaded_models.tasse_collider = Some(asset_server.load(tasse_collider_path.clone()));
-                }
-                if let Some(plant_path) = settings.assets.models.models.get("plant") {
-                    loaded_models.plant= Some(asset_server.load(plant_path.clone()));
+/// Fragt den Ladezustand aller Handles ab. Fehlgeschlagene optionale Assets werden einmal pro
+/// Ladedurchgang gemeldet und bei jedem Durchgang ersetzt, damit auch eine neu aufgebaute
+/// Registry den Fallback bekommt.
+fn check_load_states(
+    handles: &[TrackedAsset],
+    asset_server: &AssetServer,
+    reported: &mut ReportedOptionalAssets,
+    mut registry: Option<&mut AssetRegistry>,
+    mut loaded_settings: Option<&mut LoadedAssetSettings>,
+    mut sound_manager: Option<&mut SoundManager>,
+) -> LoadProgress {
+    let mut progress = LoadProgress { all_done: true, failures: Vec::new() };
+
+    for asset in handles {
+        match asset_server.get_load_state(asset.handle.id()) {
+            Some(LoadState::Loaded) => {}
+            Some(LoadState::Failed(error)) => {
+                if asset.required {
+                    progress.failures.push(FailedAsset {
+                        path: asset.path.clone(),
+                        reason: error.to_string(),
+                    });
+                    continue;
                 }
-                if let Some(plant_path) = settings.assets.models.models.get("plant") {
-                    loaded_models.plant = Some(asset_server.load(plant_path.clone()));
+                if !reported.0.contains(&asset.path) {
+                    warn!("⚠️ Optionales Asset '{}' nicht geladen, verwende Fallback: {}", asset.path, error);
+                    reported.0.push(asset.path.clone());
                 }
+                apply_optional_fallback(
+                    asset,
+                    registry.as_deref_mut(),
+                    loaded_settings.as_deref_mut(),
+                    sound_manager.as_deref_mut(),
+                );
+            }
+            _ => progress.all_done = false,
+        }
+    }
+    progress
+}
+
+/// Prüft den Ladezustand aller angeforderten Assets.
+/// Fehlgeschlagene optionale Assets werden aus Registry/Settings entfernt (Fallback),
+/// fehlgeschlagene Pflicht-Assets führen in den Zustand `AppState::LoadError`.
+pub fn check_assets_loaded_transition(
+    mut commands: Commands,
+    asset_handles: Option<Res<AssetHandles>>,
+    asset_server: Res<AssetServer>,
+    mut fallbacks: AssetFallbacks,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let Some(handles_res) = asset_handles else {
+        info!("No asset handles found, transitioning to Running");
+        next_state.set(AppState::Running);
+        return;
+    };
 
-                commands.insert_resource(loaded_models);
-
-                let ambience_path = settings
-                    .assets
-                    .audio
-                    .sounds
-                    .get("ambience")
-                    .map(|e| e.path.clone())
-                    .or_else(|| settings.assets.audio.music.get("ambience").map(|e| e.path.clone()));
-
-                // Lade optionalen Ambience-Handle und speichere als Ressource
-                let ambience_handle = ambience_path
-                    .as_ref()
-                    .map(|p| asset_server.load::<AudioSource>(p.clone()));
-                commands.insert_resource(AmbienceAudio(ambience_handle));
-
-                commands.insert_resource(LoadedAssetSettings {
-                    environment_map_path: settings.assets.environment.map.clone(),
-                });
+    let handles = &handles_res.0;
+    if handles.is_empty() {
+        info!("No assets to load, transitioning to Running");
+        next_state.set(AppState::Running);
+        return;
+    }
+
+    let progress = fallbacks.check(handles, &asset_server);
+
+    if !progress.failures.is_empty() {
+        // Erst entscheiden, wenn nichts mehr lädt – so werden alle Fehler auf einmal angezeigt
+        if progress.all_done {
+            for failure in &progress.failures {
+                error!("❌ Pflicht-Asset '{}' konnte nicht geladen werden: {}", failure.path, failure.reason);
+            }
+            commands.insert_resource(AssetLoadFailures(progress.failures));
+            next_state.set(AppState::LoadError);
+        }
+        return;
+    }
+
+    if progress.all_done {
+        info!("✅ All assets loaded successfully! Transitioning to Running state");
+        next_state.set(AppState::Running);
+    }
+}
+
+/// Gegenstück zu `check_assets_loaded_transition` im Zustand Running: Nach einem Hot-Reload
+/// laden neue Assets im Hintergrund; Fallbacks werden übernommen und fehlgeschlagene
+/// Pflicht-Assets gemeldet. Die Szene läuft in diesem Fall mit den übrigen Assets weiter.
+pub fn check_reloaded_assets(
+    asset_handles: Res<AssetHandles>,
+    asset_server: Res<AssetServer>,
+    mut fallbacks: AssetFallbacks,
+    mut settled: Local<bool>,
+) {
+    if asset_handles.is_changed() {
+        *settled = false;
+    }
+    if *settled {
+        return;
+    }
+
+    let progress = fallbacks.check(&asset_handles.0, &asset_server);
+    if !progress.all_done {
+        return;
+    }
+    for failure in &progress.failures {
+        error!(
+            "❌ Pflicht-Asset '{}' konnte nach dem Neuladen nicht geladen werden: {}",
+            failure.path, failure.reason
+        );
+    }
+    *settled = true;
+}
+
+fn apply_optional_fallback(
+    asset: &TrackedAsset,
+    registry: Option<&mut AssetRegistry>,
+    loaded_settings: Option<&mut LoadedAssetSettings>,
+    sound_manager: Option<&mut SoundManager>,
+) {
+    match asset.kind {
+        TrackedAssetKind::Model => {
+            if let Some(registry) = registry {
+                registry.remove_model(&asset.name);
+            }
+        }
+        TrackedAssetKind::Texture => {
+            if let Some(registry) = registry {
+                registry.remove_texture(&asset.name);
+            }
+        }
+        TrackedAssetKind::EnvironmentMap => {
+            if let Some(settings) = loaded_settings {
+                settings.environment_map_path = None;
             }
-            Err(e) => {
-                warn!("Failed to parse {}: {}. No assets will be loaded.", config_path, e);
-                commands.insert_resource(AssetHandles::default());
+        }
+        TrackedAssetKind::Audio => {
+            if let Some(sound_manager) = sound_manager {
+                sound_manager.remove(&asset.name);
             }
-        },
-        Err(e) => {
-            warn!("Failed to read {}: {}. No assets will be loaded.", config_path, e);
-            commands.insert_resource(AssetHandles::default());
         }
     }
 }
+
+pub fn load_assets_startup(mut commands: Commands, asset_server: Res<AssetServer>, config: Res<AppConfig>) {
+    let assets = &config.assets;
+    let handles = load_assets(assets, &asset_server);
+    info!("Requested {} assets to load", handles.len());
+    commands.insert_resource(AssetHandles(handles));
+
+    // Namensbasierte Registry für typisierte Lookups (z.B. registry.model("tasse"))
+    let registry = AssetRegistry::from_config(assets, &asset_server);
+    info!("📦 Registrierte Modelle: {}", registry.model_keys().join(", "));
+    commands.insert_resource(registry);
+
+    commands.insert_resource(LoadedAssetSettings {
+        environment_map_path: Some(assets.environment.map.clone()),
+    });
+}
+
+/// Lädt nach einer Änderung der settings.json neue bzw. geänderte Texturen und Modelle.
+/// `AssetHandles` enthält danach nur noch die aktuell konfigurierten Assets, damit ein
+/// entferntes Asset den Ladezustand nicht blockiert. Im Zustand Running wird nicht
+/// nach Loading zurückgewechselt – neue Assets laden im Hintergrund nach und werden von
+/// `check_reloaded_assets` geprüft.
+pub fn reload_assets_on_config_change(
+    mut changes: MessageReader<ConfigChanged>,
+    config: Res<AppConfig>,
+    asset_server: Res<AssetServer>,
+    mut asset_handles: ResMut<AssetHandles>,
+    mut fallbacks: AssetFallbacks,
+    current_state: Res<State<AppState>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let Some(change) = changes.read().last() else {
+        return;
+    };
+
+    let previous = &change.previous.assets;
+    let assets = &config.assets;
+    log_asset_diff("Textur", &previous.textures, &assets.textures);
+    log_asset_diff("Modell", &previous.models.models, &assets.models.models);
+
+    let handles = load_assets(assets, &asset_server);
+    let mut registry = AssetRegistry::from_config(assets, &asset_server);
+    let mut loaded_settings = LoadedAssetSettings {
+        environment_map_path: Some(assets.environment.map.clone()),
+    };
+
+    // Bekannte Ausfälle (z.B. ein weiterhin fehlendes optionales Modell) ersetzen, bevor die
+    // neue Registry aktiv wird
+    fallbacks.reported.0.clear();
+    check_load_states(
+        &handles,
+        &asset_server,
+        &mut fallbacks.reported,
+        Some(&mut registry),
+        Some(&mut loaded_settings),
+        fallbacks.sound_manager.as_deref_mut(),
+    );
+
+    asset_handles.0 = handles;
+    if let Some(current) = fallbacks.registry.as_deref_mut() {
+        *current = registry;
+    }
+    if let Some(current) = fallbacks.loaded_settings.as_deref_mut() {
+        *current = loaded_settings;
+    }
+
+    // Nach einem Ladefehler mit der korrigierten Konfiguration erneut laden
+    if *current_state.get() == AppState::LoadError {
+        info!("🔁 Konfiguration geändert – neuer Ladeversuch");
+        next_state.set(AppState::Loading);
+    }
+}
+
+fn log_asset_diff(kind: &str, previous: &HashMap<String, AssetEntry>, current: &HashMap<String, AssetEntry>) {
+    for (name, entry) in current {
+        match previous.get(name) {
+            None => info!("➕ {} '{}' hinzugefügt: {}", kind, name, entry.path()),
+            Some(old_entry) if old_entry.path() != entry.path() => {
+                info!("🔁 {} '{}' geändert: {} -> {}", kind, name, old_entry.path(), entry.path())
+            }
+            _ => {}
+        }
+    }
+    for name in previous.keys().filter(|name| !current.contains_key(*name)) {
+        info!("➖ {} '{}' entfernt", kind, name);
+    }
+}
@@ -0,0 +1,497 @@
+use avian3d::prelude::*;
+use bevy::audio::{AudioSinkPlayback, PlaybackMode, Volume};
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::setup::assetloader::AudioConfig;
+use crate::setup::config::{AppConfig, ConfigChanged};
+
+/// Name des Hintergrund-Sounds in settings.json
+pub const AMBIENCE_SOUND: &str = "ambience";
+
+/// Ein konfigurierter Sound aus settings.json
+#[derive(Clone, Debug)]
+pub struct SoundEntry {
+    pub handle: Handle<AudioSource>,
+    pub volume: f32,
+    pub looped: bool,
+    pub streaming: bool,
+}
+
+/// Spielt benannte Sounds mit ihrer konfigurierten Lautstärke und Loop-Einstellung ab.
+/// Alle Lautstärken werden mit `master_volume` skaliert.
+#[derive(Resource)]
+pub struct SoundManager {
+    sounds: HashMap<String, SoundEntry>,
+    pub master_volume: f32,
+    pub muted: bool,
+    pub fade_seconds: f32,
+}
+
+impl SoundManager {
+    pub fn from_config(audio: &AudioConfig, asset_server: &AssetServer) -> Self {
+        let sounds = audio
+            .sounds
+            .iter()
+            .chain(audio.music.iter())
+            .map(|(name, entry)| {
+                (
+                    name.clone(),
+                    SoundEntry {
+                        handle: asset_server.load(entry.path.clone()),
+                        volume: entry.volume.unwrap_or(1.0),
+                        looped: entry.looped,
+                        streaming: entry.streaming,
+                    },
+                )
+            })
+            .collect();
+
+        Self {
+            sounds,
+            master_volume: audio.volume,
+            muted: false,
+            fade_seconds: audio.fade_seconds,
+        }
+    }
+
+    pub fn sound(&self, name: &str) -> Option<&SoundEntry> {
+        self.sounds.get(name)
+    }
+
+    /// Entfernt einen Sound (z.B. nach einem Ladefehler eines optionalen Assets)
+    pub fn remove(&mut self, name: &str) {
+        self.sounds.remove(name);
+    }
+
+    pub fn effective_volume(&self, base_volume: f32) -> f32 {
+        (base_volume * self.master_volume).max(0.0)
+    }
+
+    /// PlaybackSettings für einen Sound inkl. Master-Lautstärke und Mute-Zustand
+    pub fn playback_settings(&self, entry: &SoundEntry) -> PlaybackSettings {
+        let mode = if entry.looped { PlaybackMode::Loop } else { PlaybackMode::Despawn };
+        PlaybackSettings {
+            mode,
+            volume: Volume::Linear(self.effective_volume(entry.volume)),
+            muted: self.muted,
+            ..default()
+        }
+    }
+}
+
+/// Alle über den SoundManager gestarteten Sounds.
+/// `gain` ist der aktuelle Fade-Faktor (0.0 – 1.0).
+#[derive(Component)]
+pub struct ManagedSound {
+    pub name: String,
+    pub base_volume: f32,
+    pub gain: f32,
+}
+
+#[derive(Component)]
+pub struct AmbienceAudioMarker;
+
+/// Aktueller Ambience-Track (Sound-Name aus settings.json), z.B. vom Playlist-Zeitplan gewechselt
+#[derive(Resource, Debug)]
+pub struct AmbienceTrack {
+    pub name: String,
+}
+
+impl Default for AmbienceTrack {
+    fn default() -> Self {
+        Self { name: AMBIENCE_SOUND.to_string() }
+    }
+}
+
+/// Blendet `ManagedSound::gain` über die Laufzeit des Timers von `from` nach `to`
+#[derive(Component)]
+pub struct SoundFade {
+    pub from: f32,
+    pub to: f32,
+    pub timer: Timer,
+    /// Sound nach dem Fade entfernen (z.B. alter Ambience-Track beim Wechsel)
+    pub despawn: bool,
+}
+
+/// Spielt einen in settings.json definierten Sound (nicht-räumlich)
+#[derive(Message, Clone, Debug)]
+pub struct PlaySound {
+    pub name: String,
+}
+
+/// Aufprall zweier Körper, z.B. für Sounds oder OSC-Ausgabe.
+/// Ungefiltert: Jeder Empfänger wendet seinen eigenen Mindest-Impuls an.
+#[derive(Message, Clone, Debug)]
+pub struct CollisionImpact {
+    pub entity1: Entity,
+    pub entity2: Entity,
+    pub point: Vec3,
+    pub impulse: f32,
+}
+
+/// Marker für laufende Kollisions-Sounds (für das globale Voice-Limit)
+#[derive(Component)]
+pub struct CollisionSoundVoice;
+
+/// Einstellungen für Kollisions-Sounds (`assets.audio.collisions` in settings.json)
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct CollisionSoundConfig {
+    pub enabled: bool,
+    /// Name des Sounds in `assets.audio.sounds`
+    pub sound: String,
+    /// Aufpralle unterhalb dieses Impulses bleiben stumm
+    pub min_impulse: f32,
+    /// Ab diesem Impuls volle Lautstärke / maximale Tonhöhe
+    pub max_impulse: f32,
+    /// Lautstärke-Faktor beim minimalen Impuls
+    pub min_volume: f32,
+    pub min_pitch: f32,
+    pub max_pitch: f32,
+    /// Maximal neue Kollisions-Sounds pro Frame (die stärksten gewinnen)
+    pub max_voices_per_frame: usize,
+    /// Maximal gleichzeitig laufende Kollisions-Sounds
+    pub max_active_voices: usize,
+}
+
+impl Default for CollisionSoundConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            sound: "collision".to_string(),
+            min_impulse: 0.5,
+            max_impulse: 50.0,
+            min_volume: 0.2,
+            min_pitch: 0.9,
+            max_pitch: 1.2,
+            max_voices_per_frame: 4,
+            max_active_voices: 16,
+        }
+    }
+}
+
+#[derive(Message, Clone, Debug)]
+pub enum AmbienceCommand {
+    FadeIn,
+    FadeOut,
+    SetVolume(f32),
+    /// Überblendet zu einem anderen Sound aus settings.json
+    Switch(String),
+}
+
+pub fn setup_sound_manager(mut commands: Commands, asset_server: Res<AssetServer>, config: Res<AppConfig>) {
+    let manager = SoundManager::from_config(&config.assets.audio, &asset_server);
+    let streaming = manager.sounds.values().filter(|entry| entry.streaming).count();
+    info!(
+        "🔊 SoundManager bereit ({} Sounds, {} Streaming, Master {:.2})",
+        manager.sounds.len(),
+        streaming,
+        manager.master_volume
+    );
+    commands.insert_resource(manager);
+}
+
+/// Übernimmt Sounds und Master-Lautstärke nach einer Änderung der settings.json
+pub fn reload_sound_manager(
+    mut changes: MessageReader<ConfigChanged>,
+    config: Res<AppConfig>,
+    asset_server: Res<AssetServer>,
+    mut manager: ResMut<SoundManager>,
+) {
+    if changes.read().last().is_none() {
+        return;
+    }
+    let muted = manager.muted;
+    *manager = SoundManager::from_config(&config.assets.audio, &asset_server);
+    manager.muted = muted;
+}
+
+pub fn play_sound_requests(
+    mut commands: Commands,
+    mut requests: MessageReader<PlaySound>,
+    manager: Res<SoundManager>,
+) {
+    for request in requests.read() {
+        let Some(entry) = manager.sound(&request.name) else {
+            warn!("Sound '{}' ist nicht in settings.json definiert", request.name);
+            continue;
+        };
+        commands.spawn((
+            AudioPlayer::new(entry.handle.clone()),
+            manager.playback_settings(entry),
+            ManagedSound {
+                name: request.name.clone(),
+                base_volume: entry.volume,
+                gain: 1.0,
+            },
+        ));
+    }
+}
+
+/// Startet die Hintergrund-Ambience, sobald das Asset geladen ist (mit Fade-In)
+pub fn spawn_ambience_when_ready(
+    mut commands: Commands,
+    manager: Res<SoundManager>,
+    asset_server: Res<AssetServer>,
+    track: Res<AmbienceTrack>,
+    existing: Query<(), With<AmbienceAudioMarker>>,
+    mut gave_up: Local<Option<String>>,
+) {
+    if !existing.is_empty() || gave_up.as_deref() == Some(track.name.as_str()) {
+        return;
+    }
+
+    let Some(entry) = manager.sound(&track.name) else {
+        return;
+    };
+
+    // Prüfen, ob das Audio-Asset vollständig geladen ist
+    match asset_server.get_load_state(entry.handle.id()) {
+        Some(bevy::asset::LoadState::Loaded) => {}
+        Some(bevy::asset::LoadState::Failed(e)) => {
+            warn!("⚠️ Ambience '{}' konnte nicht geladen werden: {}", track.name, e);
+            *gave_up = Some(track.name.clone());
+            return;
+        }
+        _ => return, // Asset noch nicht geladen, warten
+    }
+
+    // Startet stumm, wenn Audio gemutet ist (auch nach einem Playlist-Wechsel);
+    // während des Fade-Ins gleicht update_sound_fades den Mute-Zustand laufend ab
+    let mut settings = manager.playback_settings(entry).with_spatial(false); // Nicht-räumlich für Hintergrundmusik
+    settings.volume = Volume::Linear(0.0);
+    settings.muted = manager.muted;
+
+    commands.spawn((
+        AudioPlayer::new(entry.handle.clone()),
+        settings,
+        ManagedSound {
+            name: track.name.clone(),
+            base_volume: entry.volume,
+            gain: 0.0,
+        },
+        fade(0.0, 1.0, manager.fade_seconds),
+        AmbienceAudioMarker,
+    ));
+    info!("🔊 Ambience '{}' spawned and playing!", track.name);
+}
+
+fn fade(from: f32, to: f32, seconds: f32) -> SoundFade {
+    SoundFade {
+        from,
+        to,
+        timer: Timer::from_seconds(seconds.max(0.01), TimerMode::Once),
+        despawn: false,
+    }
+}
+
+pub fn handle_ambience_commands(
+    mut commands: Commands,
+    mut requests: MessageReader<AmbienceCommand>,
+    manager: Res<SoundManager>,
+    mut track: ResMut<AmbienceTrack>,
+    mut query: Query<(Entity, &mut ManagedSound, Option<&mut AudioSink>), With<AmbienceAudioMarker>>,
+) {
+    for request in requests.read() {
+        if let AmbienceCommand::Switch(name) = request {
+            if track.name == *name {
+                continue;
+            }
+            if manager.sound(name).is_none() {
+                warn!("Ambience '{}' ist nicht in settings.json definiert", name);
+                continue;
+            }
+            // Alter Track blendet aus; spawn_ambience_when_ready startet den neuen mit Fade-In
+            for (entity, managed, _) in query.iter() {
+                commands
+                    .entity(entity)
+                    .remove::<AmbienceAudioMarker>()
+                    .insert(SoundFade { despawn: true, ..fade(managed.gain, 0.0, manager.fade_seconds) });
+            }
+            info!("🎵 Ambience wechselt: '{}' → '{}'", track.name, name);
+            track.name = name.clone();
+            continue;
+        }
+        for (entity, mut managed, sink) in query.iter_mut() {
+            match request {
+                AmbienceCommand::FadeIn => {
+                    commands.entity(entity).insert(fade(managed.gain, 1.0, manager.fade_seconds));
+                    info!("🔉 Ambience Fade-In");
+                }
+                AmbienceCommand::FadeOut => {
+                    commands.entity(entity).insert(fade(managed.gain, 0.0, manager.fade_seconds));
+                    info!("🔈 Ambience Fade-Out");
+                }
+                AmbienceCommand::SetVolume(volume) => {
+                    managed.base_volume = volume.max(0.0);
+                    if let Some(mut sink) = sink {
+                        sink.set_volume(Volume::Linear(manager.effective_volume(managed.base_volume) * managed.gain));
+                    }
+                    info!("🔊 Ambience-Lautstärke: {:.2}", managed.base_volume);
+                }
+                AmbienceCommand::Switch(_) => {}
+            }
+        }
+    }
+}
+
+pub fn update_sound_fades(
+    mut commands: Commands,
+    time: Res<Time>,
+    manager: Res<SoundManager>,
+    mut query: Query<(Entity, &mut ManagedSound, &mut SoundFade, Option<&mut AudioSink>)>,
+) {
+    for (entity, mut managed, mut fade, sink) in query.iter_mut() {
+        fade.timer.tick(time.delta());
+        let t = fade.timer.fraction();
+        managed.gain = fade.from + (fade.to - fade.from) * t;
+
+        // Auch während eines Fades gilt der aktuelle Mute-Zustand (Taste 'U')
+        if let Some(mut sink) = sink {
+            sync_sink(&mut *sink, &manager, &managed);
+        }
+        if fade.timer.is_finished() {
+            if fade.despawn {
+                commands.entity(entity).despawn();
+            } else {
+                commands.entity(entity).remove::<SoundFade>();
+            }
+        }
+    }
+}
+
+fn sync_sink(sink: &mut impl AudioSinkPlayback, manager: &SoundManager, managed: &ManagedSound) {
+    sink.set_volume(Volume::Linear(manager.effective_volume(managed.base_volume) * managed.gain));
+    if manager.muted { sink.mute() } else { sink.unmute() }
+}
+
+/// Überträgt Master-Lautstärke und Mute-Zustand auf laufende Sounds.
+/// Neue Sinks werden ebenfalls abgeglichen: Mute kann sich zwischen Spawn und Sink-Erstellung ändern.
+pub fn apply_sound_volumes(
+    manager: Res<SoundManager>,
+    mut sinks: Query<(&ManagedSound, &mut AudioSink), Without<SoundFade>>,
+    mut spatial_sinks: Query<(&ManagedSound, &mut SpatialAudioSink), Without<SoundFade>>,
+) {
+    let changed = manager.is_changed();
+
+    for (managed, mut sink) in sinks.iter_mut() {
+        if changed || sink.is_added() {
+            sync_sink(&mut *sink, &manager, managed);
+        }
+    }
+    for (managed, mut sink) in spatial_sinks.iter_mut() {
+        if changed || sink.is_added() {
+            sync_sink(&mut *sink, &manager, managed);
+        }
+    }
+}
+
+/// Mute mit Taste 'U', Ambience ein-/ausblenden mit Taste 'J'
+pub fn audio_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut manager: ResMut<SoundManager>,
+    ambience_query: Query<&ManagedSound, With<AmbienceAudioMarker>>,
+    mut ambience_writer: MessageWriter<AmbienceCommand>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyU) {
+        manager.muted = !manager.muted;
+        if manager.muted {
+            info!("🔇 Audio stumm");
+        } else {
+            info!("🔊 Audio an");
+        }
+    }
+
+    if keyboard.just_pressed(KeyCode::KeyJ) {
+        let faded_in = ambience_query.iter().any(|managed| managed.gain > 0.5);
+        ambience_writer.write(if faded_in { AmbienceCommand::FadeOut } else { AmbienceCommand::FadeIn });
+    }
+}
+
+/// Ermittelt Aufprall-Impulse aus den Collision-Start-Events von avian3d
+pub fn detect_collision_impacts(
+    mut collision_events: MessageReader<CollisionStart>,
+    collisions: Collisions,
+    bodies: Query<(&GlobalTransform, Option<&LinearVelocity>, Option<&Mass>)>,
+    mut impact_writer: MessageWriter<CollisionImpact>,
+) {
+    for event in collision_events.read() {
+        let entity1 = event.body1.unwrap_or(event.collider1);
+        let entity2 = event.body2.unwrap_or(event.collider2);
+        let Ok((transform1, velocity1, mass1)) = bodies.get(entity1) else { continue };
+        let Ok((transform2, velocity2, mass2)) = bodies.get(entity2) else { continue };
+
+        let contact = collisions.get(event.collider1, event.collider2);
+        let mut impulse = contact.map_or(0.0, |pair| pair.total_normal_impulse_magnitude());
+
+        // Im ersten Kontakt-Frame ist der Solver-Impuls oft noch 0 – dann über die Relativgeschwindigkeit schätzen
+        if impulse <= f32::EPSILON {
+            let relative_speed = (velocity1.map_or(Vec3::ZERO, |v| v.0) - velocity2.map_or(Vec3::ZERO, |v| v.0)).length();
+            let mass = mass1.map_or(1.0, |m| m.0).min(mass2.map_or(1.0, |m| m.0));
+            impulse = relative_speed * mass;
+        }
+
+        let point = contact
+            .and_then(|pair| pair.manifolds.iter().flat_map(|m| m.points.iter()).next().map(|p| p.point))
+            .unwrap_or_else(|| transform1.translation().lerp(transform2.translation(), 0.5));
+
+        impact_writer.write(CollisionImpact { entity1, entity2, point, impulse });
+    }
+}
+
+/// Spielt Kollisions-Sounds räumlich am Kontaktpunkt.
+/// Lautstärke und Tonhöhe skalieren mit dem Impuls, Voice-Limits verhindern Audio-Chaos im Stresstest.
+pub fn play_collision_sounds(
+    mut commands: Commands,
+    mut impacts: MessageReader<CollisionImpact>,
+    manager: Res<SoundManager>,
+    config: Res<AppConfig>,
+    active_voices: Query<(), With<CollisionSoundVoice>>,
+) {
+    let collision_config = &config.assets.audio.collisions;
+    if !collision_config.enabled {
+        impacts.clear();
+        return;
+    }
+
+    let mut frame_impacts: Vec<&CollisionImpact> = impacts
+        .read()
+        .filter(|impact| impact.impulse >= collision_config.min_impulse)
+        .collect();
+    if frame_impacts.is_empty() {
+        return;
+    }
+    let Some(entry) = manager.sound(&collision_config.sound) else {
+        return;
+    };
+
+    let free_voices = collision_config.max_active_voices.saturating_sub(active_voices.iter().count());
+    let voices = collision_config.max_voices_per_frame.min(free_voices);
+
+    frame_impacts.sort_by(|a, b| b.impulse.total_cmp(&a.impulse));
+    for impact in frame_impacts.into_iter().take(voices) {
+        let range = (collision_config.max_impulse - collision_config.min_impulse).max(f32::EPSILON);
+        let t = ((impact.impulse - collision_config.min_impulse) / range).clamp(0.0, 1.0);
+        let volume_factor = collision_config.min_volume + (1.0 - collision_config.min_volume) * t;
+        let pitch = collision_config.min_pitch + (collision_config.max_pitch - collision_config.min_pitch) * t;
+        let base_volume = entry.volume * volume_factor;
+
+        commands.spawn((
+            AudioPlayer::new(entry.handle.clone()),
+            manager
+                .playback_settings(entry)
+                .with_volume(Volume::Linear(manager.effective_volume(base_volume)))
+                .with_speed(pitch)
+                .with_spatial(true),
+            Transform::from_translation(impact.point),
+            ManagedSound {
+                name: collision_config.sound.clone(),
+                base_volume,
+                gain: 1.0,
+            },
+            CollisionSoundVoice,
+        ));
+    }
+}
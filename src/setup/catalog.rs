@@ -0,0 +1,208 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fs;
+
+use crate::setup::assetloader::ModelsConfig;
+use crate::setup::asset_registry::AssetRegistry;
+use crate::setup::config::ConfigError;
+use crate::setup::gltf_spawner::{spawn_gltf_with_physics, GltfSpawnConfig};
+use crate::setup::world::RadialGravity;
+
+/// Pfad zum Produktkatalog (relativ zum Arbeitsverzeichnis)
+pub const CATALOG_PATH: &str = "assets/config/catalog.json";
+
+/// Physikalische Eigenschaften eines Produkts
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct ProductPhysics {
+    pub mass: f32,
+    pub restitution: f32,
+    pub friction: f32,
+    /// Wird von der radialen Gravitation angezogen
+    pub radial_gravity: bool,
+}
+
+impl Default for ProductPhysics {
+    fn default() -> Self {
+        Self { mass: 1.0, restitution: 0.2, friction: 0.5, radial_gravity: true }
+    }
+}
+
+/// Startpose eines Produkts in der Szene
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct ProductPose {
+    pub translation: [f32; 3],
+    /// Euler-Winkel (XYZ) in Grad
+    pub rotation: [f32; 3],
+    pub scale: f32,
+}
+
+impl Default for ProductPose {
+    fn default() -> Self {
+        Self { translation: [0.0, 0.0, 0.0], rotation: [0.0, 0.0, 0.0], scale: 1.0 }
+    }
+}
+
+impl ProductPose {
+    pub fn transform(&self) -> Transform {
+        let [x, y, z] = self.rotation.map(f32::to_radians);
+        Transform::from_translation(Vec3::from_array(self.translation))
+            .with_rotation(Quat::from_euler(EulerRot::XYZ, x, y, z))
+            .with_scale(Vec3::splat(self.scale))
+    }
+}
+
+/// Ein Produkt aus `catalog.json`
+#[derive(Deserialize, Clone, Debug)]
+pub struct CatalogEntry {
+    pub id: String,
+    pub name: String,
+    pub price: f32,
+    #[serde(default = "default_currency")]
+    pub currency: String,
+    #[serde(default)]
+    pub description: String,
+    /// Schlüssel in `assets.models`
+    pub model: String,
+    /// Eigener Collider-Schlüssel; sonst `<model>_collider` bzw. das Modell selbst
+    #[serde(default)]
+    pub collider: Option<String>,
+    #[serde(default)]
+    pub physics: ProductPhysics,
+    #[serde(default)]
+    pub pose: ProductPose,
+    /// Link für den QR-Code im Info-Panel (sonst `interaction.info_panel.shop_url_template`)
+    #[serde(default)]
+    pub shop_url: Option<String>,
+}
+
+fn default_currency() -> String {
+    "EUR".to_string()
+}
+
+impl CatalogEntry {
+    /// Preis mit Währung, z.B. "14,90 EUR"
+    pub fn formatted_price(&self) -> String {
+        format!("{:.2} {}", self.price, self.currency).replacen('.', ",", 1)
+    }
+}
+
+/// Alle Produkte des Schaufensters
+#[derive(Resource, Deserialize, Clone, Debug, Default)]
+pub struct ProductCatalog {
+    pub products: Vec<CatalogEntry>,
+}
+
+impl ProductCatalog {
+    /// Liest, parst und validiert den Katalog gegen die konfigurierten Modelle (`assets.models`)
+    pub fn load(path: &str, models: &ModelsConfig) -> Result<Self, ConfigError> {
+        let contents = fs::read_to_string(path).map_err(|source| ConfigError::Io {
+            path: path.to_string(),
+            source,
+        })?;
+        let catalog: ProductCatalog = serde_json::from_str(&contents).map_err(|source| ConfigError::Parse {
+            path: path.to_string(),
+            source,
+        })?;
+
+        let problems = catalog.validate(models);
+        if problems.is_empty() {
+            Ok(catalog)
+        } else {
+            Err(ConfigError::Invalid { path: path.to_string(), problems })
+        }
+    }
+
+    pub fn validate(&self, models: &ModelsConfig) -> Vec<String> {
+        let mut problems = Vec::new();
+        let mut ids = HashSet::new();
+        for product in &self.products {
+            if !ids.insert(product.id.as_str()) {
+                problems.push(format!("Produkt-ID '{}' ist doppelt vergeben", product.id));
+            }
+            if product.price < 0.0 {
+                problems.push(format!("{}: price darf nicht negativ sein", product.id));
+            }
+            if product.physics.mass <= 0.0 {
+                problems.push(format!("{}: physics.mass muss größer als 0 sein", product.id));
+            }
+            if product.pose.scale <= 0.0 {
+                problems.push(format!("{}: pose.scale muss größer als 0 sein", product.id));
+            }
+            if !models.models.contains_key(&product.model) {
+                problems.push(format!("{}: Modell '{}' fehlt in assets.models", product.id, product.model));
+            }
+            if let Some(collider) = &product.collider {
+                if !models.models.contains_key(collider) {
+                    problems.push(format!("{}: Collider '{}' fehlt in assets.models", product.id, collider));
+                }
+            }
+        }
+        problems
+    }
+
+    pub fn get(&self, id: &str) -> Option<&CatalogEntry> {
+        self.products.iter().find(|product| product.id == id)
+    }
+}
+
+/// Verknüpft eine Entity mit ihrem Katalogeintrag
+#[derive(Component, Reflect, Clone, Debug, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct Product {
+    pub id: String,
+}
+
+/// Spawnt ein Katalogprodukt mit Physik an seiner Standardpose (oder `transform`)
+pub fn spawn_product(
+    commands: &mut Commands,
+    registry: &AssetRegistry,
+    gltf_assets: &Assets<Gltf>,
+    gltf_mesh_assets: &Assets<bevy::gltf::GltfMesh>,
+    mesh_assets: &Assets<Mesh>,
+    product: &CatalogEntry,
+    transform: Option<Transform>,
+) -> Option<Entity> {
+    let model = match registry.model(&product.model) {
+        Ok(model) => model,
+        Err(e) => {
+            warn!("Produkt '{}' wird nicht gespawnt: {}", product.id, e);
+            return None;
+        }
+    };
+    let collider = match &product.collider {
+        Some(key) => match registry.model(key) {
+            Ok(collider) => collider.visual.clone(),
+            Err(e) => {
+                warn!("Produkt '{}': {} – nutze Standard-Collider", product.id, e);
+                model.collider_or_visual()
+            }
+        },
+        None => model.collider_or_visual(),
+    };
+
+    let spawn_config = GltfSpawnConfig::new(model.visual.clone())
+        .with_collider_gltf(collider)
+        .with_transform(transform.unwrap_or_else(|| product.pose.transform()))
+        .with_mass(product.physics.mass)
+        .with_physics(product.physics.restitution, product.physics.friction)
+        .with_radial_gravity(product.physics.radial_gravity);
+
+    let entity = spawn_gltf_with_physics(
+        commands,
+        gltf_assets,
+        gltf_mesh_assets,
+        mesh_assets,
+        spawn_config,
+        product.pose.scale,
+        Some(RadialGravity),
+    )?;
+    commands.entity(entity).insert((
+        Name::new(product.name.clone()),
+        Product { id: product.id.clone() },
+    ));
+    info!("🛍️ '{}' ({}, {}) gespawnt", product.id, product.name, product.formatted_price());
+    Some(entity)
+}
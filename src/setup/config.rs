@@ -0,0 +1,483 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoadFailedEvent, AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::fmt;
+use std::fs;
+use std::net::SocketAddr;
+
+use crate::gamelogic::gesture_combos::validate_combos;
+use crate::gamelogic::InteractionConfig;
+use crate::network::{default_osc_mappings, validate_osc_mappings, validate_osc_triggers, BindRetryConfig, OscMapping, OscOutputConfig, RecordingConfig, SimulatorConfig};
+use crate::setup::assetloader::AssetsConfig;
+use crate::setup::playlist::{validate_playlist, PlaylistConfig};
+use crate::setup::presets::PresetsConfig;
+use crate::setup::snapshot::SnapshotConfig;
+
+/// Pfad zur zentralen Konfigurationsdatei (relativ zum Arbeitsverzeichnis)
+pub const CONFIG_PATH: &str = "assets/config/settings.json";
+
+/// Derselbe Pfad relativ zum Asset-Ordner (für das Hot-Reload-Asset)
+pub const CONFIG_ASSET_PATH: &str = "config/settings.json";
+
+/// Typisierte App-Konfiguration aus `settings.json`.
+/// Wird vor `App::run` geladen und als Resource eingefügt.
+/// Zusätzlich als Asset geladen, damit Änderungen zur Laufzeit übernommen werden.
+#[derive(Resource, Asset, TypePath, Deserialize, Clone, Debug)]
+pub struct AppConfig {
+    /// Überwacht settings.json und lädt Änderungen zur Laufzeit nach
+    #[serde(default = "default_hot_reload")]
+    pub hot_reload: bool,
+    #[serde(default, alias = "game")]
+    pub window: WindowConfig,
+    #[serde(default)]
+    pub physics: PhysicsConfig,
+    #[serde(default)]
+    pub network: NetworkConfig,
+    #[serde(default)]
+    pub camera: CameraConfig,
+    #[serde(default)]
+    pub rendering: RenderingConfig,
+    #[serde(default)]
+    pub interaction: InteractionConfig,
+    #[serde(default)]
+    pub presets: PresetsConfig,
+    #[serde(default)]
+    pub playlist: PlaylistConfig,
+    #[serde(default)]
+    pub snapshot: SnapshotConfig,
+    pub assets: AssetsConfig,
+}
+
+fn default_hot_reload() -> bool {
+    true
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct WindowConfig {
+    #[serde(alias = "name")]
+    pub title: String,
+    pub width: u32,
+    pub height: u32,
+    /// Ziel-FPS (Referenz für den FPS-Graphen)
+    pub fps: u32,
+    pub vsync: bool,
+    pub resizable: bool,
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            title: "Interactive Storefront".to_string(),
+            width: 1920,
+            height: 1080,
+            fps: 120,
+            vsync: true,
+            resizable: true,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct PhysicsConfig {
+    /// Globale Gravitation (x, y, z) – Standard ist schwerelos
+    pub gravity: [f32; 3],
+    /// Zentrum der radialen Gravitation
+    pub radial_gravity_center: [f32; 3],
+    /// Beschleunigung Richtung Zentrum in m/s²
+    pub radial_gravity_strength: f32,
+}
+
+impl Default for PhysicsConfig {
+    fn default() -> Self {
+        Self {
+            gravity: [0.0, 0.0, 0.0],
+            radial_gravity_center: [0.0, 0.0, 0.0],
+            radial_gravity_strength: 0.5,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct NetworkConfig {
+    pub osc_listen_address: String,
+    pub ws_listen_address: String,
+    /// Intervall der Health-Nachrichten an WebSocket-Clients
+    pub health_interval_secs: f32,
+    pub bind_retry: BindRetryConfig,
+    /// OSC-Adressmuster -> GameEvent (ersetzt die fest verdrahteten Adressen)
+    pub osc_mappings: Vec<OscMapping>,
+    pub osc_output: OscOutputConfig,
+    pub recording: RecordingConfig,
+    pub simulator: SimulatorConfig,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            osc_listen_address: "0.0.0.0:9001".to_string(),
+            ws_listen_address: "0.0.0.0:9002".to_string(),
+            health_interval_secs: 1.0,
+            bind_retry: BindRetryConfig::default(),
+            osc_mappings: default_osc_mappings(),
+            osc_output: OscOutputConfig::default(),
+            recording: RecordingConfig::default(),
+            simulator: SimulatorConfig::default(),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct CameraConfig {
+    pub position: [f32; 3],
+    pub target: [f32; 3],
+    pub min_radius: f32,
+    pub max_radius: f32,
+    /// Geschwindigkeit der automatischen Orbit-Bewegung in rad/s
+    pub auto_orbit_speed: f32,
+    pub aperture_f_stops: f32,
+}
+
+impl Default for CameraConfig {
+    fn default() -> Self {
+        Self {
+            position: [-2.5, 2.0, 15.0],
+            target: [0.0, 0.0, 0.0],
+            min_radius: 4.0,
+            max_radius: 30.0,
+            auto_orbit_speed: 0.01,
+            aperture_f_stops: 2.5,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct RenderingConfig {
+    pub clear_color: [f32; 3],
+    /// 1 (aus), 2, 4 oder 8
+    pub msaa_samples: u32,
+    pub bloom: bool,
+    pub depth_of_field: bool,
+    pub fog: FogConfig,
+    pub ambient_brightness: f32,
+    pub directional_illuminance: f32,
+    pub shadows: bool,
+    pub environment_intensity: f32,
+}
+
+impl Default for RenderingConfig {
+    fn default() -> Self {
+        Self {
+            clear_color: [0.6, 0.6, 0.6],
+            msaa_samples: 4,
+            bloom: true,
+            depth_of_field: true,
+            fog: FogConfig::default(),
+            ambient_brightness: 75.0,
+            directional_illuminance: 8000.0,
+            shadows: true,
+            environment_intensity: 10000.0,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct FogConfig {
+    pub enabled: bool,
+    pub color: [f32; 3],
+    pub start: f32,
+    pub end: f32,
+}
+
+impl Default for FogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            color: [0.25, 0.25, 0.25],
+            start: 10.0,
+            end: 20.0,
+        }
+    }
+}
+
+/// Fehler beim Laden der Konfiguration – mit lesbarer Beschreibung für die Konsole
+#[derive(Debug)]
+pub enum ConfigError {
+    Io { path: String, source: std::io::Error },
+    Parse { path: String, source: serde_json::Error },
+    Invalid { path: String, problems: Vec<String> },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io { path, source } => {
+                write!(f, "Konfiguration '{}' konnte nicht gelesen werden: {}", path, source)
+            }
+            ConfigError::Parse { path, source } => {
+                write!(
+                    f,
+                    "Konfiguration '{}' ist fehlerhaft (Zeile {}, Spalte {}): {}",
+                    path,
+                    source.line(),
+                    source.column(),
+                    source
+                )
+            }
+            ConfigError::Invalid { path, problems } => {
+                writeln!(f, "Konfiguration '{}' ist ungültig:", path)?;
+                for problem in problems {
+                    writeln!(f, "  - {}", problem)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl AppConfig {
+    /// Liest, parst und validiert die Konfiguration
+    pub fn load(path: &str) -> Result<Self, ConfigError> {
+        let contents = fs::read_to_string(path).map_err(|source| ConfigError::Io {
+            path: path.to_string(),
+            source,
+        })?;
+        Self::from_json(path, &contents)
+    }
+
+    pub fn from_json(path: &str, contents: &str) -> Result<Self, ConfigError> {
+        let config: AppConfig = serde_json::from_str(contents).map_err(|source| ConfigError::Parse {
+            path: path.to_string(),
+            source,
+        })?;
+
+        let problems = config.validate();
+        if problems.is_empty() {
+            Ok(config)
+        } else {
+            Err(ConfigError::Invalid {
+                path: path.to_string(),
+                problems,
+            })
+        }
+    }
+
+    /// Prüft Wertebereiche und Abhängigkeiten zwischen Feldern
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        if self.window.width == 0 || self.window.height == 0 {
+            problems.push(format!(
+                "window: Auflösung {}x{} ist ungültig",
+                self.window.width, self.window.height
+            ));
+        }
+        if self.window.fps == 0 {
+            problems.push("window.fps muss größer als 0 sein".to_string());
+        }
+
+        for (name, address) in [
+            ("network.osc_listen_address", &self.network.osc_listen_address),
+            ("network.ws_listen_address", &self.network.ws_listen_address),
+        ] {
+            if address.parse::<SocketAddr>().is_err() {
+                problems.push(format!("{}: '{}' ist keine gültige Adresse (host:port)", name, address));
+            }
+        }
+
+        // Ohne Abstand gibt es keine Blickrichtung (normalize() liefert NaN)
+        if self.camera.position == self.camera.target {
+            problems.push(format!(
+                "camera: position und target ({:?}) dürfen nicht gleich sein",
+                self.camera.target
+            ));
+        }
+        if self.camera.min_radius <= 0.0 || self.camera.min_radius >= self.camera.max_radius {
+            problems.push(format!(
+                "camera: min_radius ({}) muss > 0 und kleiner als max_radius ({}) sein",
+                self.camera.min_radius, self.camera.max_radius
+            ));
+        }
+
+        if ![1, 2, 4, 8].contains(&self.rendering.msaa_samples) {
+            problems.push(format!(
+                "rendering.msaa_samples: {} wird nicht unterstützt (1, 2, 4 oder 8)",
+                self.rendering.msaa_samples
+            ));
+        }
+        if self.rendering.fog.enabled && self.rendering.fog.start >= self.rendering.fog.end {
+            problems.push(format!(
+                "rendering.fog: start ({}) muss kleiner als end ({}) sein",
+                self.rendering.fog.start, self.rendering.fog.end
+            ));
+        }
+
+        problems.extend(validate_osc_mappings(&self.network.osc_mappings));
+        if self.network.recording.replay_speed <= 0.0 {
+            problems.push("network.recording.replay_speed muss größer als 0 sein".to_string());
+        }
+        let simulator = &self.network.simulator;
+        if simulator.pinch_min >= simulator.pinch_max {
+            problems.push("network.simulator.pinch_min muss kleiner als pinch_max sein".to_string());
+        }
+        for target in &self.network.osc_output.targets {
+            if target.parse::<SocketAddr>().is_err() {
+                problems.push(format!("network.osc_output.targets: '{}' ist keine gültige Adresse", target));
+            }
+        }
+        problems.extend(validate_osc_triggers(&self.network.osc_output));
+
+        problems.extend(validate_combos(&self.interaction.combos));
+        for (name, action) in &self.interaction.combo_bindings {
+            if !self.interaction.combos.iter().any(|combo| &combo.name == name) {
+                problems.push(format!("interaction.combo_bindings: Combo '{}' ist nicht definiert", name));
+            }
+            if action.is_continuous() {
+                problems.push(format!(
+                    "interaction.combo_bindings: Combo '{}' kann keine kontinuierliche Aktion auslösen",
+                    name
+                ));
+            }
+        }
+        if self.interaction.action_cooldown_secs < 0.0 {
+            problems.push("interaction.action_cooldown_secs darf nicht negativ sein".to_string());
+        }
+        let pinch = &self.interaction.pinch;
+        if pinch.min_scale <= 0.0 || pinch.min_scale > pinch.max_scale {
+            problems.push(format!(
+                "interaction.pinch: min_scale ({}) muss > 0 und <= max_scale ({}) sein",
+                pinch.min_scale, pinch.max_scale
+            ));
+        }
+        if !(0.0..=1.0).contains(&pinch.smoothing) {
+            problems.push("interaction.pinch.smoothing muss zwischen 0 und 1 liegen".to_string());
+        }
+        if self.interaction.detection.timeout_secs <= 0.0 {
+            problems.push("interaction.detection.timeout_secs muss größer als 0 sein".to_string());
+        }
+
+        problems.extend(validate_playlist(&self.playlist));
+        let audio = &self.assets.audio;
+        for entry in &self.playlist.entries {
+            if let Some(ambience) = &entry.ambience {
+                if !audio.sounds.contains_key(ambience) && !audio.music.contains_key(ambience) {
+                    problems.push(format!(
+                        "playlist.entries '{}': Sound '{}' ist nicht in assets.audio definiert",
+                        entry.name, ambience
+                    ));
+                }
+            }
+        }
+
+        for (name, entry) in &self.assets.textures {
+            if entry.path().trim().is_empty() {
+                problems.push(format!("assets.textures.{}: Pfad ist leer", name));
+            }
+        }
+        for (name, entry) in &self.assets.models.models {
+            if entry.path().trim().is_empty() {
+                problems.push(format!("assets.models.{}: Pfad ist leer", name));
+            }
+        }
+
+        problems
+    }
+}
+
+/// Lädt settings.json als Bevy-Asset (inkl. Validierung)
+#[derive(Default)]
+pub struct AppConfigLoader;
+
+impl AssetLoader for AppConfigLoader {
+    type Asset = AppConfig;
+    type Settings = ();
+    type Error = ConfigError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let path = load_context.path().display().to_string();
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(|source| ConfigError::Io { path: path.clone(), source })?;
+        let contents = String::from_utf8_lossy(&bytes);
+        AppConfig::from_json(&path, &contents)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["json"]
+    }
+}
+
+/// Handle auf das überwachte Konfigurations-Asset
+#[derive(Resource)]
+pub struct ConfigHandle(pub Handle<AppConfig>);
+
+/// Wird gesendet, nachdem eine geänderte settings.json übernommen wurde.
+/// Die neue Konfiguration steht bereits in der `AppConfig`-Resource.
+#[derive(Message, Clone, Debug)]
+pub struct ConfigChanged {
+    pub previous: AppConfig,
+}
+
+pub fn watch_config_file(mut commands: Commands, asset_server: Res<AssetServer>, config: Res<AppConfig>) {
+    if !config.hot_reload {
+        return;
+    }
+    commands.insert_resource(ConfigHandle(asset_server.load(CONFIG_ASSET_PATH)));
+    info!("👀 Hot-Reload für {} aktiv", CONFIG_PATH);
+}
+
+/// Übernimmt eine geänderte (und gültige) settings.json in die `AppConfig`-Resource.
+/// Ungültige Änderungen werden gemeldet, die bisherige Konfiguration bleibt aktiv.
+pub fn detect_config_changes(
+    mut asset_events: MessageReader<AssetEvent<AppConfig>>,
+    mut failed_events: MessageReader<AssetLoadFailedEvent<AppConfig>>,
+    handle: Option<Res<ConfigHandle>>,
+    assets: Res<Assets<AppConfig>>,
+    mut config: ResMut<AppConfig>,
+    mut changed_writer: MessageWriter<ConfigChanged>,
+) {
+    for failed in failed_events.read() {
+        warn!("⚠️ settings.json nicht übernommen, bisherige Konfiguration bleibt aktiv: {}", failed.error);
+    }
+
+    let Some(handle) = handle else {
+        asset_events.clear();
+        return;
+    };
+
+    for event in asset_events.read() {
+        let AssetEvent::Modified { id } = event else {
+            continue;
+        };
+        if *id != handle.0.id() {
+            continue;
+        }
+        let Some(new_config) = assets.get(*id) else {
+            continue;
+        };
+
+        let previous = std::mem::replace(config.as_mut(), new_config.clone());
+        info!("🔄 settings.json neu geladen");
+        changed_writer.write(ConfigChanged { previous });
+    }
+}
+
+/// Hilfsfunktion für `[f32; 3]`-Farben aus der Konfiguration
+pub fn srgb(color: [f32; 3]) -> Color {
+    Color::srgb(color[0], color[1], color[2])
+}
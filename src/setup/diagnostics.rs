@@ -5,6 +5,9 @@ use bevy::ecs::entity::Entity;
 use bevy::ecs::component::Component;
 use bevy::prelude::Children;
 
+use crate::gamelogic::hands::HandsState;
+use crate::network::{ListenerState, NetworkStatus};
+
 #[derive(Component)]
 pub struct FpsText;
 
@@ -29,6 +32,12 @@ pub struct LightInfoText;
 #[derive(Component)]
 pub struct GameEventsText;
 
+#[derive(Component)]
+pub struct NetworkStatusText;
+
+#[derive(Component)]
+pub struct HandsText;
+
 // --- FPS Graph ---
 #[derive(Resource)]
 pub struct FpsGraphConfig {
@@ -127,19 +136,21 @@ pub struct GameEventStats {
     pub hand_count_changed: u32,
     pub hand_gesture: u32,
     pub hand_pinch_distance: u32,
+    pub hand_position: u32,
     pub object_detected: u32,
     pub last_event: Option<String>,
 }
 
 impl GameEventStats {
     pub fn total(&self) -> u32 {
-        self.hand_count_changed + self.hand_gesture + self.hand_pinch_distance + self.object_detected
+        self.hand_count_changed + self.hand_gesture + self.hand_pinch_distance + self.hand_position + self.object_detected
     }
 
     pub fn _reset(&mut self) {
         self.hand_count_changed = 0;
         self.hand_gesture = 0;
         self.hand_pinch_distance = 0;
+        self.hand_position = 0;
         self.object_detected = 0;
     }
 }
@@ -165,7 +176,10 @@ pub fn toggle_diagnostics_overlay(
 ) {
     if keyboard.just_pressed(KeyCode::KeyG) {
         visibility.visible = !visibility.visible;
+    }
 
+    // Auch Änderungen per Fernsteuerung übernehmen
+    if visibility.is_changed() {
         for mut vis in &mut query {
             *vis = if visibility.visible {
                 Visibility::Visible
@@ -286,6 +300,28 @@ pub fn setup_fps_overlay(mut commands: Commands) {
             GameEventsText,
         ));
 
+        // Network Status Text
+        parent.spawn((
+            Text::new("Netzwerk: --"),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 1.0, 1.0)),
+            NetworkStatusText,
+        ));
+
+        // Hands Text
+        parent.spawn((
+            Text::new("Hände: --"),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 1.0, 1.0)),
+            HandsText,
+        ));
+
         // FPS Graph Container + Bars
         parent.spawn((
             Node {
@@ -387,21 +423,34 @@ pub fn update_loading_progress(
     use crate::setup::appstate::AppState;
 
     for mut text in &mut query {
-        if *current_state.get() == AppState::Loading {
-            if let Some(handles_res) = &asset_handles {
-                let total = handles_res.0.len();
-                let loaded = handles_res.0.iter().filter(|handle| {
-                    matches!(
-                        asset_server.get_load_state(handle.id()),
-                        Some(bevy::asset::LoadState::Loaded)
-                    )
-                }).count();
-                **text = format!("Loading: {}/{} assets", loaded, total);
-            } else {
-                **text = "Initializing...".to_string();
+        match current_state.get() {
+            AppState::Loading => {
+                if let Some(handles_res) = &asset_handles {
+                    let total = handles_res.0.len();
+                    let mut loaded = 0;
+                    let mut failed = 0;
+                    for asset in &handles_res.0 {
+                        match asset_server.get_load_state(asset.handle.id()) {
+                            Some(bevy::asset::LoadState::Loaded) => loaded += 1,
+                            Some(bevy::asset::LoadState::Failed(_)) => failed += 1,
+                            _ => {}
+                        }
+                    }
+                    if failed > 0 {
+                        **text = format!("Loading: {}/{} assets ({} failed)", loaded, total, failed);
+                    } else {
+                        **text = format!("Loading: {}/{} assets", loaded, total);
+                    }
+                } else {
+                    **text = "Initializing...".to_string();
+                }
+            }
+            AppState::LoadError => {
+                **text = "Load error!".to_string();
+            }
+            _ => {
+                **text = "Ready!".to_string();
             }
-        } else {
-            **text = "Ready!".to_string();
         }
     }
 }
@@ -491,10 +540,11 @@ pub fn update_game_events_text(
 ) {
     for mut text in &mut query {
         if game_event_stats.total() > 0 {
-            **text = format!("Events: HandCount: {} | Gesture: {} | Pinch: {} | Object: {}",
+            **text = format!("Events: HandCount: {} | Gesture: {} | Pinch: {} | Position: {} | Object: {}",
                             game_event_stats.hand_count_changed,
                             game_event_stats.hand_gesture,
                             game_event_stats.hand_pinch_distance,
+                            game_event_stats.hand_position,
                             game_event_stats.object_detected);
         } else {
             **text = "Events: --".to_string();
@@ -502,6 +552,78 @@ pub fn update_game_events_text(
     }
 }
 
+pub fn update_network_status_text(
+    network_status: Option<Res<NetworkStatus>>,
+    time: Res<Time>,
+    mut query: Query<(&mut Text, &mut TextColor), With<NetworkStatusText>>,
+) {
+    let Some(network_status) = network_status else {
+        return;
+    };
+    let now = time.elapsed_secs();
+
+    for (mut text, mut color) in &mut query {
+        let lines: Vec<String> = network_status
+            .listeners
+            .iter()
+            .map(|listener| {
+                let last = listener
+                    .last_message_at
+                    .map_or("--".to_string(), |at| format!("{:.1}s", now - at));
+                match &listener.state {
+                    ListenerState::Failed(reason) => format!("{}: {}", listener.name, reason),
+                    state => format!(
+                        "{} {} ({}) | Clients: {} | Letzte Nachricht: {}",
+                        listener.name, listener.address, state, listener.client_count, last
+                    ),
+                }
+            })
+            .collect();
+        **text = format!("Netzwerk: {}", lines.join("\n          "));
+
+        let failed = network_status
+            .listeners
+            .iter()
+            .any(|listener| matches!(listener.state, ListenerState::Failed(_)));
+        color.0 = if failed { Color::srgb(1.0, 0.4, 0.4) } else { Color::srgb(1.0, 1.0, 1.0) };
+    }
+}
+
+pub fn update_hands_text(
+    hands: Option<Res<HandsState>>,
+    time: Res<Time>,
+    mut query: Query<&mut Text, With<HandsText>>,
+) {
+    let Some(hands) = hands else {
+        return;
+    };
+    let now = time.elapsed_secs();
+
+    for mut text in &mut query {
+        let lines: Vec<String> = hands
+            .iter()
+            .map(|hand| {
+                let gesture = hand
+                    .gesture
+                    .as_ref()
+                    .map_or("--".to_string(), |g| format!("{} {:.1}s", g, hand.gesture_held_secs(now)));
+                let pinch = hand
+                    .pinch_distance
+                    .map_or("--".to_string(), |d| format!("{:.3} ({:+.2}/s)", d, hand.pinch_velocity));
+                let position = hand
+                    .position
+                    .map_or("--".to_string(), |p| format!("({:.2}, {:.2})", p.x, p.y));
+                format!("#{}: {} | Pinch: {} | Pos: {}", hand.hand_id, gesture, pinch, position)
+            })
+            .collect();
+        **text = if lines.is_empty() {
+            format!("Hände: -- (gemeldet: {})", hands.reported_count)
+        } else {
+            format!("Hände: {} (gemeldet: {})\n       {}", lines.len(), hands.reported_count, lines.join("\n       "))
+        };
+    }
+}
+
 pub fn update_fps_graph(
     diagnostics: Res<DiagnosticsStore>,
     time: Res<Time>,
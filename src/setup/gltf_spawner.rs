@@ -113,6 +113,7 @@ pub fn spawn_gltf_with_physics(
         Mass(config.mass),
         Restitution::new(config.restitution),
         Friction::new(config.friction),
+        CollisionEventsEnabled,
     ));
 
     // Optional: Velocity
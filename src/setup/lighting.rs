@@ -1,11 +1,12 @@
 use bevy::prelude::*;
 use crate::setup::assetloader::LoadedAssetSettings;
+use crate::setup::config::{AppConfig, ConfigChanged};
 
-pub fn spawn_directional_light(mut commands: Commands) {
+pub fn spawn_directional_light(mut commands: Commands, config: Res<AppConfig>) {
     commands.spawn((
         DirectionalLight {
-            shadows_enabled: true,
-            illuminance: 8000.0,
+            shadows_enabled: config.rendering.shadows,
+            illuminance: config.rendering.directional_illuminance,
             ..default()
         },
         Transform::from_xyz(-2.0, 8.0, 2.0)
@@ -13,10 +14,10 @@ pub fn spawn_directional_light(mut commands: Commands) {
     ));
 }
 
-pub fn spawn_ambient_light(mut commands: Commands) {
+pub fn spawn_ambient_light(mut commands: Commands, config: Res<AppConfig>) {
     commands.insert_resource(AmbientLight {
         color: Color::srgb(1.0, 1.0, 1.0),
-        brightness: 75.0,
+        brightness: config.rendering.ambient_brightness,
         affects_lightmapped_meshes: true,
     });
 }
@@ -25,14 +26,52 @@ pub fn spawn_environment_map_light(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     settings: Res<LoadedAssetSettings>,
+    config: Res<AppConfig>,
 ) {
-    let environment_map = asset_server.load(&settings.environment_map_path);
+    let Some(environment_map_path) = settings.environment_map_path.as_ref() else {
+        info!("🌤️ Keine Environment-Map verfügbar – EnvironmentMapLight wird nicht gespawnt");
+        return;
+    };
+    let environment_map = asset_server.load(environment_map_path);
 
     commands.spawn(EnvironmentMapLight {
         diffuse_map: environment_map.clone(),
         specular_map: environment_map,
-        intensity: 10000.0,
+        intensity: config.rendering.environment_intensity,
         ..default()
     });
-    info!("🌤️ EnvironmentMapLight spawned: {}", settings.environment_map_path);
+    info!("🌤️ EnvironmentMapLight spawned: {}", environment_map_path);
+}
+
+/// Übernimmt Licht-Parameter nach einer Änderung der settings.json
+pub fn apply_lighting_config(
+    mut changes: MessageReader<ConfigChanged>,
+    config: Res<AppConfig>,
+    asset_server: Res<AssetServer>,
+    ambient: Option<ResMut<AmbientLight>>,
+    mut directional_query: Query<&mut DirectionalLight>,
+    mut env_query: Query<&mut EnvironmentMapLight>,
+) {
+    if changes.read().last().is_none() {
+        return;
+    }
+
+    let rendering = &config.rendering;
+    if let Some(mut ambient) = ambient {
+        ambient.brightness = rendering.ambient_brightness;
+    }
+    for mut light in &mut directional_query {
+        light.illuminance = rendering.directional_illuminance;
+        light.shadows_enabled = rendering.shadows;
+    }
+
+    if !env_query.is_empty() {
+        let environment_map: Handle<Image> = asset_server.load(&config.assets.environment.map);
+        for mut env in &mut env_query {
+            env.diffuse_map = environment_map.clone();
+            env.specular_map = environment_map.clone();
+            env.intensity = rendering.environment_intensity;
+        }
+    }
+    info!("💡 Licht-Parameter aktualisiert");
 }
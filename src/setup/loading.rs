@@ -1,13 +1,14 @@
 
 use bevy::prelude::*;
-use bevy::asset::LoadState;
-use crate::AppState;
-use crate::setup::assetloader::{LoadedModels, LoadedAssetSettings, AmbienceAudio};
+use crate::setup::assetloader::AssetLoadFailures;
 
 
 #[derive(Component)]
 pub struct LoadingMarker;
 
+#[derive(Component)]
+pub struct LoadErrorMarker;
+
 pub fn spawn_loading_screen(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands.spawn((
         Node {
@@ -39,36 +40,63 @@ pub fn despawn_loading_screen(mut commands: Commands, query: Query<Entity, With<
     info!("✅ Ladescreen entfernt");
 }
 
-pub fn _check_assets_loaded_transition(
-    mut next_state: ResMut<NextState<AppState>>,
+/// Zeigt die Liste der Pflicht-Assets an, die nicht geladen werden konnten
+pub fn spawn_load_error_screen(
+    mut commands: Commands,
     asset_server: Res<AssetServer>,
-    loaded_models: Option<Res<LoadedModels>>,
-    loaded_settings: Option<Res<LoadedAssetSettings>>,
-    ambience: Option<Res<AmbienceAudio>>,
+    failures: Option<Res<AssetLoadFailures>>,
 ) {
-    let Some(models) = loaded_models else { return; };
-    let Some(_settings) = loaded_settings else { return; };
-
-    let mut all_loaded = true;
-
-    if let Some(h) = models.tasse.as_ref() {
-        if !matches!(asset_server.load_state(h.id()), LoadState::Loaded) {
-            all_loaded = false;
-        }
-    } else {
-        all_loaded = false;
-    }
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+    let failures = failures.map(|f| f.0.clone()).unwrap_or_default();
 
-    if let Some(amb) = ambience {
-        if let Some(h) = &amb.0 {
-            if !matches!(asset_server.load_state(h.id()), LoadState::Loaded) {
-                all_loaded = false;
-            }
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(8.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.1, 0.0, 0.0, 0.85)),
+        LoadErrorMarker,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new("LADEFEHLER"),
+            TextFont {
+                font: font.clone(),
+                font_size: 40.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 0.3, 0.3)),
+        ));
+        for failure in &failures {
+            parent.spawn((
+                Text::new(format!("{} – {}", failure.path, failure.reason)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
         }
-    }
+        parent.spawn((
+            Text::new("settings.json korrigieren oder Asset als \"required\": false markieren"),
+            TextFont {
+                font: font.clone(),
+                font_size: 16.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.8, 0.8, 0.8)),
+        ));
+    });
+    warn!("🛑 Ladefehler-Screen angezeigt ({} Assets)", failures.len());
+}
 
-    if all_loaded {
-        next_state.set(AppState::Running);
-        info!("🚀 Assets geladen — Wechsel zu AppState::Running");
+pub fn despawn_load_error_screen(mut commands: Commands, query: Query<Entity, With<LoadErrorMarker>>) {
+    for e in query.iter() {
+        commands.entity(e).despawn();
     }
 }
@@ -6,6 +6,7 @@ use bevy::core_pipeline::tonemapping::Tonemapping;
 use bevy::post_process::bloom::Bloom;
 use bevy::core_pipeline::prepass::{DepthPrepass, NormalPrepass};
 use bevy::post_process::dof::{DepthOfField, DepthOfFieldMode};
+use crate::setup::config::{srgb, AppConfig, ConfigChanged};
 
 #[derive(Component)]
 pub struct OrbitCamera {
@@ -23,50 +24,66 @@ pub struct AutoOrbit {
     pub axis: Vec3,
 }
 
-pub fn spawn_dynamic_orbit_camera(mut commands: Commands) {
-    let position = Vec3::new(-2.5, 2.0, 15.0);
-    let target = Vec3::ZERO;
+pub fn spawn_dynamic_orbit_camera(mut commands: Commands, config: Res<AppConfig>) {
+    let camera_config = &config.camera;
+    let rendering = &config.rendering;
+    let position = Vec3::from_array(camera_config.position);
+    let target = Vec3::from_array(camera_config.target);
     let radius = position.distance(target);
     let direction = (position - target).normalize();
     let angle_y = direction.y.asin();
     let angle_x = direction.z.atan2(direction.x);
 
-    commands.spawn((
+    let msaa = match rendering.msaa_samples {
+        1 => Msaa::Off,
+        2 => Msaa::Sample2,
+        8 => Msaa::Sample8,
+        _ => Msaa::Sample4,
+    };
+
+    let mut camera = commands.spawn((
         Camera3d::default(),
         DepthPrepass,
         NormalPrepass,
         TemporalJitter{
             offset: Vec2::ZERO,
         },
-        DistanceFog {
-            color: Color::srgb(0.25, 0.25, 0.25),
-            falloff: FogFalloff::Linear { start: 10.0, end: 20.0 },
-            ..default()
-        },
-        Msaa::Sample4, // Sample2 if too slow, Sample8 for better quality
+        msaa, // Sample2 if too slow, Sample8 for better quality
         Hdr,
         Transform::from_translation(position).looking_at(target, Vec3::Y),
         SpatialListener::new(50.0),
         Tonemapping::TonyMcMapface,
-        Bloom::default(),
-        DepthOfField {
-            focal_distance: 5.0,
-            aperture_f_stops: 2.5,
-            sensor_height: 24.0,
-            max_circle_of_confusion_diameter: 500.0,
-            max_depth: 1000.0,
-            mode: DepthOfFieldMode::Bokeh,
-        },
         OrbitCamera {
             target,
             radius,
             angle_x,
             angle_y,
-            min_radius: 4.0,
-            max_radius: 30.0,
+            min_radius: camera_config.min_radius,
+            max_radius: camera_config.max_radius,
         },
-        AutoOrbit { speed: 0.01, axis: Vec3::Y },
+        AutoOrbit { speed: camera_config.auto_orbit_speed, axis: Vec3::Y },
     ));
+
+    if rendering.fog.enabled {
+        camera.insert(DistanceFog {
+            color: srgb(rendering.fog.color),
+            falloff: FogFalloff::Linear { start: rendering.fog.start, end: rendering.fog.end },
+            ..default()
+        });
+    }
+    if rendering.bloom {
+        camera.insert(Bloom::default());
+    }
+    if rendering.depth_of_field {
+        camera.insert(DepthOfField {
+            focal_distance: 5.0,
+            aperture_f_stops: camera_config.aperture_f_stops,
+            sensor_height: 24.0,
+            max_circle_of_confusion_diameter: 500.0,
+            max_depth: 1000.0,
+            mode: DepthOfFieldMode::Bokeh,
+        });
+    }
 }
 
 pub fn sync_dof_focus(
@@ -125,3 +142,45 @@ pub fn orbit_camera_controls(
         transform.look_at(orbit.target, Vec3::Y);
     }
 }
+
+/// Übernimmt Kamera-, Fog- und Clear-Color-Parameter nach einer Änderung der settings.json
+pub fn apply_camera_config(
+    mut changes: MessageReader<ConfigChanged>,
+    config: Res<AppConfig>,
+    mut clear_color: ResMut<ClearColor>,
+    mut query: Query<(
+        &mut OrbitCamera,
+        Option<&mut AutoOrbit>,
+        Option<&mut DistanceFog>,
+        Option<&mut DepthOfField>,
+    )>,
+) {
+    let Some(change) = changes.read().last() else {
+        return;
+    };
+
+    let camera_config = &config.camera;
+    let rendering = &config.rendering;
+    clear_color.0 = srgb(rendering.clear_color);
+
+    for (mut orbit, auto_orbit, fog, dof) in query.iter_mut() {
+        orbit.min_radius = camera_config.min_radius;
+        orbit.max_radius = camera_config.max_radius;
+        orbit.radius = orbit.radius.clamp(orbit.min_radius, orbit.max_radius);
+        // Ziel nur übernehmen, wenn es in der Datei geändert wurde (sonst laufende Interaktion nicht stören)
+        if change.previous.camera.target != camera_config.target {
+            orbit.target = Vec3::from_array(camera_config.target);
+        }
+        if let Some(mut auto_orbit) = auto_orbit {
+            auto_orbit.speed = camera_config.auto_orbit_speed;
+        }
+        if let Some(mut fog) = fog {
+            fog.color = srgb(rendering.fog.color);
+            fog.falloff = FogFalloff::Linear { start: rendering.fog.start, end: rendering.fog.end };
+        }
+        if let Some(mut dof) = dof {
+            dof.aperture_f_stops = camera_config.aperture_f_stops;
+        }
+    }
+    info!("🎥 Kamera-Parameter aktualisiert");
+}
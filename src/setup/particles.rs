@@ -1,11 +1,31 @@
 use ::bevy_hanabi::prelude::*;
 use ::bevy_hanabi::{Gradient, ParticleTextureModifier, ImageSampleMapping, EffectMaterial};
 use bevy::prelude::*;
-use crate::setup::assetloader::LoadedTextures;
+use crate::setup::asset_registry::AssetRegistry;
+use crate::setup::config::ConfigChanged;
 
 #[derive(Resource)]
 pub struct MyEffectHandle(pub Handle<EffectAsset>);
 
+/// Einmaliger Partikel-Ausstoß (z.B. per Fernsteuerung)
+#[derive(Resource)]
+pub struct BurstEffectHandle(pub Handle<EffectAsset>);
+
+const BURST_LIFETIME_SECS: f32 = 2.5;
+
+/// Löst an `position` einen Partikel-Burst aus
+#[derive(Message, Clone, Debug)]
+pub struct ParticleBurst {
+    pub position: Vec3,
+}
+
+#[derive(Component)]
+pub struct ParticleBurstLifetime(Timer);
+
+/// Marker für den schwebenden Hintergrund-Staub (Presets blenden ihn ein/aus)
+#[derive(Component)]
+pub struct DustParticles;
+
 pub fn setup(
     mut commands: Commands,
     mut effects: ResMut<Assets<EffectAsset>>,
@@ -85,23 +105,117 @@ pub fn setup(
 
     let effect_handle = effects.add(effect);
     commands.insert_resource(MyEffectHandle(effect_handle));
+    commands.insert_resource(BurstEffectHandle(effects.add(burst_effect())));
+}
+
+fn burst_effect() -> EffectAsset {
+    let w = ExprWriter::new();
+
+    let mut gradient = Gradient::new();
+    gradient.add_key(0.0, Vec4::new(1.0, 0.9, 0.6, 1.0));
+    gradient.add_key(1.0, Vec4::new(1.0, 0.9, 0.6, 0.0));
+
+    let init_pos = SetPositionSphereModifier {
+        center: w.lit(Vec3::ZERO).expr(),
+        radius: w.lit(0.2).expr(),
+        dimension: ShapeDimension::Volume,
+    };
+    let init_vel = SetVelocitySphereModifier {
+        center: w.lit(Vec3::ZERO).expr(),
+        speed: (w.rand(ScalarType::Float) * w.lit(3.0) + w.lit(1.0)).expr(),
+    };
+    let init_size = SetAttributeModifier::new(Attribute::SIZE, w.lit(0.03).expr());
+    let init_lifetime = SetAttributeModifier::new(Attribute::LIFETIME, w.lit(BURST_LIFETIME_SECS).expr());
+    let update_drag = LinearDragModifier::new(w.lit(2.0).expr());
+
+    let mut module = w.finish();
+    module.add_texture_slot("color");
+    let texture_slot = module.lit(0u32);
+
+    EffectAsset::new(512, SpawnerSettings::once(300.0.into()), module)
+        .with_name("Burst")
+        .init(init_pos)
+        .init(init_vel)
+        .init(init_size)
+        .init(init_lifetime)
+        .update(update_drag)
+        .render(OrientModifier::new(OrientMode::ParallelCameraDepthPlane))
+        .render(ColorOverLifetimeModifier { gradient, ..default() })
+        .render(ParticleTextureModifier {
+            texture_slot,
+            sample_mapping: ImageSampleMapping::Modulate,
+        })
+}
+
+/// Spawnt für jede `ParticleBurst`-Nachricht einen einmaligen Effekt
+pub fn spawn_particle_bursts(
+    mut commands: Commands,
+    mut bursts: MessageReader<ParticleBurst>,
+    effect_handle: Res<BurstEffectHandle>,
+    registry: Res<AssetRegistry>,
+) {
+    for burst in bursts.read() {
+        commands.spawn((
+            ParticleEffect::new(effect_handle.0.clone()),
+            EffectMaterial { images: dust_particle_images(&registry) },
+            Transform::from_translation(burst.position),
+            ParticleBurstLifetime(Timer::from_seconds(BURST_LIFETIME_SECS + 0.5, TimerMode::Once)),
+        ));
+    }
+}
+
+/// Entfernt abgelaufene Bursts
+pub fn despawn_finished_bursts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut ParticleBurstLifetime)>,
+) {
+    for (entity, mut lifetime) in query.iter_mut() {
+        if lifetime.0.tick(time.delta()).is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
 }
 
 pub fn spawn_particlesystem(
     mut commands: Commands,
     effect_handle: Res<MyEffectHandle>,
-    loaded_textures: Res<LoadedTextures>,
+    registry: Res<AssetRegistry>,
 ) {
     // Binde die Textur über EffectMaterial an Slot 0
-    let images = loaded_textures
-        .dust_particle
-        .as_ref()
-        .map(|h| vec![h.clone()])
-        .unwrap_or_default();
+    let images = dust_particle_images(&registry);
 
     commands.spawn((
         ParticleEffect::new(effect_handle.0.clone()),
         EffectMaterial { images },
-        Transform::from_translation(Vec3::ZERO)
+        Transform::from_translation(Vec3::ZERO),
+        DustParticles,
     ));
-}
\ No newline at end of file
+}
+
+/// Bindet die Partikel-Textur nach einer Änderung der settings.json neu
+pub fn apply_particle_texture(
+    mut changes: MessageReader<ConfigChanged>,
+    registry: Res<AssetRegistry>,
+    mut query: Query<&mut EffectMaterial>,
+) {
+    if changes.read().last().is_none() {
+        return;
+    }
+
+    let images = dust_particle_images(&registry);
+
+    for mut material in query.iter_mut() {
+        material.images = images.clone();
+    }
+}
+
+fn dust_particle_images(registry: &AssetRegistry) -> Vec<Handle<Image>> {
+    match registry.texture("dust_particle") {
+        Ok(handle) => vec![handle.clone()],
+        Err(e) => {
+            warn!("Partikel ohne Textur: {}", e);
+            Vec::new()
+        }
+    }
+}
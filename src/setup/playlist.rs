@@ -0,0 +1,463 @@
+use bevy::prelude::*;
+use chrono::{Datelike, Local, Timelike};
+use serde::Deserialize;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::setup::audio::AmbienceCommand;
+use crate::setup::config::{AppConfig, ConfigChanged};
+use crate::setup::presets::{ActivePreset, ScenePresets, SwitchScenePreset};
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Weekday {
+    Mon,
+    Tue,
+    Wed,
+    Thu,
+    Fri,
+    Sat,
+    Sun,
+}
+
+impl Weekday {
+    const ALL: [Weekday; 7] = [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ];
+
+    fn from_index(index: i64) -> Self {
+        Self::ALL[index.rem_euclid(7) as usize]
+    }
+
+    fn previous(self) -> Self {
+        Self::from_index(self as i64 - 1)
+    }
+}
+
+/// Simulierte Uhr statt Systemzeit, z.B. für Tests ohne Fenster (`playlist.fake_clock`)
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct FakeClockConfig {
+    pub day: Weekday,
+    /// Startzeit "HH:MM"
+    pub time: String,
+    /// Simulierte Sekunden pro echter Sekunde (3600 = eine Stunde pro Sekunde)
+    pub speed: f32,
+}
+
+impl Default for FakeClockConfig {
+    fn default() -> Self {
+        Self { day: Weekday::Mon, time: "08:00".to_string(), speed: 1.0 }
+    }
+}
+
+/// Ein Zeitfenster im Schaufenster-Programm (`playlist.entries`)
+///
+/// Beispiel: `{ "name": "nacht", "days": ["fri", "sat"], "from": "22:00", "to": "06:00",
+/// "preset": "night_sale", "ambience": "ambience", "lighting_scale": 0.4 }`
+#[derive(Deserialize, Clone, Debug)]
+pub struct PlaylistEntry {
+    pub name: String,
+    /// Wochentage, an denen das Fenster beginnt (leer = täglich)
+    #[serde(default)]
+    pub days: Vec<Weekday>,
+    /// "HH:MM"; ist `to` früher als `from`, läuft das Fenster über Mitternacht
+    pub from: String,
+    pub to: String,
+    /// Szenen-Preset; leer = aktuelles Preset behalten
+    #[serde(default)]
+    pub preset: Option<String>,
+    /// Sound-Name aus `assets.audio` für die Ambience
+    #[serde(default)]
+    pub ambience: Option<String>,
+    /// Faktor für die Lichtintensität des Presets
+    #[serde(default = "default_lighting_scale")]
+    pub lighting_scale: f32,
+}
+
+fn default_lighting_scale() -> f32 {
+    1.0
+}
+
+impl PlaylistEntry {
+    /// Läuft das Fenster am Tag `day` zur Minute `minute` (seit Mitternacht)?
+    fn is_active(&self, day: Weekday, minute: u32) -> bool {
+        let (Some(from), Some(to)) = (parse_time(&self.from), parse_time(&self.to)) else {
+            return false;
+        };
+        let runs_on = |day: Weekday| self.days.is_empty() || self.days.contains(&day);
+        if from == to {
+            runs_on(day)
+        } else if from < to {
+            runs_on(day) && (from..to).contains(&minute)
+        } else {
+            (runs_on(day) && minute >= from) || (runs_on(day.previous()) && minute < to)
+        }
+    }
+}
+
+/// Zeitgesteuertes Programm für das Schaufenster (`playlist`)
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct PlaylistConfig {
+    pub enabled: bool,
+    /// Fester Abstand zu UTC in Minuten; leer = Zeitzone des Systems (inkl. Sommerzeit)
+    pub utc_offset_minutes: Option<i32>,
+    pub fake_clock: Option<FakeClockConfig>,
+    /// Der erste passende Eintrag gewinnt
+    pub entries: Vec<PlaylistEntry>,
+}
+
+impl Default for PlaylistConfig {
+    fn default() -> Self {
+        Self { enabled: false, utc_offset_minutes: None, fake_clock: None, entries: Vec::new() }
+    }
+}
+
+/// Der erste Eintrag, dessen Zeitfenster am Tag `day` zur Minute `minute` läuft
+pub fn select_entry(playlist: &PlaylistConfig, day: Weekday, minute: u32) -> Option<&PlaylistEntry> {
+    playlist.entries.iter().find(|entry| entry.is_active(day, minute))
+}
+
+/// "HH:MM" in Minuten seit Mitternacht
+fn parse_time(value: &str) -> Option<u32> {
+    let (hours, minutes) = value.trim().split_once(':')?;
+    let hours: u32 = hours.parse().ok()?;
+    let minutes: u32 = minutes.parse().ok()?;
+    (hours < 24 && minutes < 60).then_some(hours * 60 + minutes)
+}
+
+/// Prüft Uhrzeiten und Faktoren beim Laden der Konfiguration
+pub fn validate_playlist(playlist: &PlaylistConfig) -> Vec<String> {
+    let mut problems = Vec::new();
+    for entry in &playlist.entries {
+        for (field, value) in [("from", &entry.from), ("to", &entry.to)] {
+            if parse_time(value).is_none() {
+                problems.push(format!("playlist.entries '{}': {} '{}' ist keine Uhrzeit (HH:MM)", entry.name, field, value));
+            }
+        }
+        if entry.lighting_scale < 0.0 {
+            problems.push(format!("playlist.entries '{}': lighting_scale darf nicht negativ sein", entry.name));
+        }
+    }
+    if let Some(fake) = &playlist.fake_clock {
+        if parse_time(&fake.time).is_none() {
+            problems.push(format!("playlist.fake_clock.time '{}' ist keine Uhrzeit (HH:MM)", fake.time));
+        }
+        if fake.speed < 0.0 {
+            problems.push("playlist.fake_clock.speed darf nicht negativ sein".to_string());
+        }
+    }
+    problems
+}
+
+/// Ortszeit für die Playlist: Systemuhr oder simulierte Uhr
+#[derive(Resource, Debug)]
+pub struct ShopClock {
+    pub day: Weekday,
+    /// Sekunden seit Mitternacht
+    pub seconds: f64,
+    /// Simulierte Sekunden pro echter Sekunde; `None` = Systemuhr
+    fake_speed: Option<f32>,
+}
+
+impl ShopClock {
+    pub fn from_config(playlist: &PlaylistConfig) -> Self {
+        match &playlist.fake_clock {
+            Some(fake) => Self {
+                day: fake.day,
+                seconds: parse_time(&fake.time).unwrap_or(0) as f64 * 60.0,
+                fake_speed: Some(fake.speed),
+            },
+            None => {
+                let mut clock = Self { day: Weekday::Mon, seconds: 0.0, fake_speed: None };
+                clock.sync_system_time(playlist.utc_offset_minutes);
+                clock
+            }
+        }
+    }
+
+    fn sync_system_time(&mut self, utc_offset_minutes: Option<i32>) {
+        let Some(offset) = utc_offset_minutes else {
+            let now = Local::now();
+            self.day = Weekday::from_index(now.weekday().num_days_from_monday() as i64);
+            self.seconds = now.num_seconds_from_midnight() as f64 + now.nanosecond() as f64 / 1e9;
+            return;
+        };
+        let utc = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0.0, |since| since.as_secs_f64());
+        let local = utc + offset as f64 * 60.0;
+        let days = (local / SECONDS_PER_DAY).floor();
+        // 1.1.1970 war ein Donnerstag
+        self.day = Weekday::from_index(days as i64 + 3);
+        self.seconds = local - days * SECONDS_PER_DAY;
+    }
+
+    pub fn minute(&self) -> u32 {
+        (self.seconds / 60.0) as u32
+    }
+
+    /// Simulierte Uhr um `delta_secs` weiterstellen bzw. die Systemuhr neu lesen
+    fn advance(&mut self, delta_secs: f64, utc_offset_minutes: Option<i32>) {
+        match self.fake_speed {
+            Some(speed) => {
+                self.seconds += delta_secs * speed as f64;
+                while self.seconds >= SECONDS_PER_DAY {
+                    self.seconds -= SECONDS_PER_DAY;
+                    self.day = Weekday::from_index(self.day as i64 + 1);
+                }
+            }
+            None => self.sync_system_time(utc_offset_minutes),
+        }
+    }
+}
+
+impl FromWorld for ShopClock {
+    fn from_world(world: &mut World) -> Self {
+        world
+            .get_resource::<AppConfig>()
+            .map(|config| Self::from_config(&config.playlist))
+            .unwrap_or_else(|| Self::from_config(&PlaylistConfig::default()))
+    }
+}
+
+/// Aktuell laufender Playlist-Eintrag
+#[derive(Resource, Default, Debug)]
+pub struct PlaylistState {
+    pub active: Option<String>,
+    /// Der erste Eintrag nach dem Start wird ohne Überblendung übernommen
+    started: bool,
+}
+
+/// Stellt die Uhr weiter; nach einer Konfigurationsänderung beginnt sie neu
+pub fn advance_shop_clock(
+    time: Res<Time>,
+    config: Res<AppConfig>,
+    mut changes: MessageReader<ConfigChanged>,
+    mut clock: ResMut<ShopClock>,
+) {
+    if changes.read().last().is_some() {
+        *clock = ShopClock::from_config(&config.playlist);
+        return;
+    }
+    clock.advance(time.delta_secs_f64(), config.playlist.utc_offset_minutes);
+}
+
+/// Wechselt Preset, Ambience und Lichtintensität, sobald ein anderes Zeitfenster beginnt
+pub fn run_playlist(
+    config: Res<AppConfig>,
+    clock: Res<ShopClock>,
+    presets: Res<ScenePresets>,
+    active_preset: Res<ActivePreset>,
+    mut state: ResMut<PlaylistState>,
+    mut preset_writer: MessageWriter<SwitchScenePreset>,
+    mut ambience_writer: MessageWriter<AmbienceCommand>,
+) {
+    let playlist = &config.playlist;
+    if !playlist.enabled {
+        state.active = None;
+        return;
+    }
+
+    let entry = select_entry(playlist, clock.day, clock.minute());
+    if entry.map(|entry| &entry.name) == state.active.as_ref() {
+        return;
+    }
+    let instant = !state.started;
+    state.started = true;
+    state.active = entry.map(|entry| entry.name.clone());
+
+    let Some(entry) = entry else {
+        info!("📅 {:?} {:02}:{:02} – kein Playlist-Eintrag aktiv", clock.day, clock.minute() / 60, clock.minute() % 60);
+        return;
+    };
+    info!(
+        "📅 {:?} {:02}:{:02} – Playlist-Eintrag '{}'",
+        clock.day,
+        clock.minute() / 60,
+        clock.minute() % 60,
+        entry.name
+    );
+
+    let preset = entry.preset.as_ref().or(active_preset.name.as_ref());
+    match preset {
+        Some(name) if presets.get(name).is_some() => {
+            preset_writer.write(SwitchScenePreset {
+                name: name.clone(),
+                instant,
+                lighting_scale: Some(entry.lighting_scale),
+            });
+        }
+        Some(name) => warn!("Playlist '{}': Preset '{}' unbekannt (verfügbar: {})", entry.name, name, presets.names().join(", ")),
+        None if entry.lighting_scale != 1.0 => {
+            warn!("Playlist '{}': ohne aktives Preset kann das Licht nicht angepasst werden", entry.name)
+        }
+        None => {}
+    }
+    if let Some(ambience) = &entry.ambience {
+        ambience_writer.write(AmbienceCommand::Switch(ambience.clone()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, days: &[Weekday], from: &str, to: &str) -> PlaylistEntry {
+        PlaylistEntry {
+            name: name.to_string(),
+            days: days.to_vec(),
+            from: from.to_string(),
+            to: to.to_string(),
+            preset: None,
+            ambience: None,
+            lighting_scale: 1.0,
+        }
+    }
+
+    fn minute(time: &str) -> u32 {
+        parse_time(time).unwrap()
+    }
+
+    fn selected(playlist: &PlaylistConfig, day: Weekday, time: &str) -> Option<String> {
+        select_entry(playlist, day, minute(time)).map(|entry| entry.name.clone())
+    }
+
+    #[test]
+    fn window_within_one_day() {
+        let day = entry("tag", &[], "07:00", "20:00");
+        assert!(!day.is_active(Weekday::Mon, minute("06:59")));
+        assert!(day.is_active(Weekday::Mon, minute("07:00")));
+        assert!(day.is_active(Weekday::Sun, minute("19:59")));
+        assert!(!day.is_active(Weekday::Mon, minute("20:00")));
+    }
+
+    #[test]
+    fn window_across_midnight_belongs_to_start_day() {
+        let night = entry("nacht", &[Weekday::Fri, Weekday::Sat], "22:00", "06:00");
+        assert!(night.is_active(Weekday::Fri, minute("22:00")));
+        assert!(night.is_active(Weekday::Fri, minute("23:59")));
+        // Nach Mitternacht zählt der Vortag
+        assert!(night.is_active(Weekday::Sat, minute("00:00")));
+        assert!(night.is_active(Weekday::Sun, minute("05:59")));
+        assert!(!night.is_active(Weekday::Sun, minute("06:00")));
+        assert!(!night.is_active(Weekday::Sun, minute("22:00")));
+        assert!(!night.is_active(Weekday::Fri, minute("03:00")));
+        assert!(!night.is_active(Weekday::Thu, minute("23:00")));
+    }
+
+    #[test]
+    fn monday_after_sunday_night() {
+        let night = entry("nacht", &[Weekday::Sun], "23:00", "01:00");
+        assert!(night.is_active(Weekday::Mon, minute("00:30")));
+        assert!(!night.is_active(Weekday::Sun, minute("00:30")));
+    }
+
+    #[test]
+    fn equal_from_and_to_covers_whole_day() {
+        let all_day = entry("sonntag", &[Weekday::Sun], "00:00", "00:00");
+        assert!(all_day.is_active(Weekday::Sun, minute("00:00")));
+        assert!(all_day.is_active(Weekday::Sun, minute("23:59")));
+        assert!(!all_day.is_active(Weekday::Mon, minute("12:00")));
+    }
+
+    #[test]
+    fn invalid_times_never_match() {
+        assert!(!entry("kaputt", &[], "25:00", "06:00").is_active(Weekday::Mon, minute("23:00")));
+        assert!(!entry("kaputt", &[], "07:00", "7 Uhr").is_active(Weekday::Mon, minute("08:00")));
+    }
+
+    #[test]
+    fn first_matching_entry_wins() {
+        let playlist = PlaylistConfig {
+            entries: vec![
+                entry("launch", &[Weekday::Sat], "10:00", "14:00"),
+                entry("tag", &[], "07:00", "20:00"),
+                entry("nacht", &[], "20:00", "07:00"),
+            ],
+            ..default()
+        };
+        assert_eq!(selected(&playlist, Weekday::Sat, "11:00").as_deref(), Some("launch"));
+        assert_eq!(selected(&playlist, Weekday::Fri, "11:00").as_deref(), Some("tag"));
+        assert_eq!(selected(&playlist, Weekday::Sat, "14:00").as_deref(), Some("tag"));
+        assert_eq!(selected(&playlist, Weekday::Sat, "02:00").as_deref(), Some("nacht"));
+    }
+
+    #[test]
+    fn no_entry_outside_all_windows() {
+        let playlist = PlaylistConfig { entries: vec![entry("tag", &[Weekday::Mon], "07:00", "20:00")], ..default() };
+        assert_eq!(selected(&playlist, Weekday::Mon, "21:00"), None);
+        assert_eq!(selected(&playlist, Weekday::Tue, "08:00"), None);
+    }
+
+    #[test]
+    fn fake_clock_starts_at_configured_time() {
+        let playlist = PlaylistConfig {
+            fake_clock: Some(FakeClockConfig { day: Weekday::Wed, time: "08:30".to_string(), speed: 60.0 }),
+            ..default()
+        };
+        let clock = ShopClock::from_config(&playlist);
+        assert_eq!(clock.day, Weekday::Wed);
+        assert_eq!(clock.minute(), minute("08:30"));
+    }
+
+    #[test]
+    fn fake_clock_rolls_over_to_next_day() {
+        let playlist = PlaylistConfig {
+            fake_clock: Some(FakeClockConfig { day: Weekday::Sun, time: "23:30".to_string(), speed: 3600.0 }),
+            ..default()
+        };
+        let mut clock = ShopClock::from_config(&playlist);
+        // Eine echte Sekunde = eine Stunde
+        clock.advance(1.0, None);
+        assert_eq!(clock.day, Weekday::Mon);
+        assert_eq!(clock.minute(), minute("00:30"));
+
+        // Mehrere Tage in einem Schritt
+        clock.advance(49.0, None);
+        assert_eq!(clock.day, Weekday::Wed);
+        assert_eq!(clock.minute(), minute("01:30"));
+    }
+
+    #[test]
+    fn fake_clock_drives_playlist_through_the_week() {
+        let playlist = PlaylistConfig {
+            enabled: true,
+            fake_clock: Some(FakeClockConfig { day: Weekday::Fri, time: "19:00".to_string(), speed: 3600.0 }),
+            entries: vec![
+                entry("nacht", &[Weekday::Fri], "20:00", "06:00"),
+                entry("tag", &[], "06:00", "20:00"),
+            ],
+            ..default()
+        };
+        let mut clock = ShopClock::from_config(&playlist);
+        let mut seen = Vec::new();
+        for _ in 0..12 {
+            let name = select_entry(&playlist, clock.day, clock.minute()).map(|entry| entry.name.clone());
+            if seen.last() != Some(&name) {
+                seen.push(name);
+            }
+            clock.advance(1.0, None);
+        }
+        assert_eq!(
+            seen,
+            vec![Some("tag".to_string()), Some("nacht".to_string()), Some("tag".to_string())]
+        );
+    }
+
+    #[test]
+    fn validation_reports_bad_times() {
+        let playlist = PlaylistConfig {
+            entries: vec![entry("kaputt", &[], "24:00", "06:60")],
+            fake_clock: Some(FakeClockConfig { time: "acht".to_string(), ..default() }),
+            ..default()
+        };
+        assert_eq!(validate_playlist(&playlist).len(), 3);
+    }
+}
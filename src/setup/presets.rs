@@ -0,0 +1,679 @@
+use avian3d::prelude::{AngularVelocity, LinearVelocity};
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use crate::gamelogic::pinch::OriginalScale;
+use crate::gamelogic::Selectable;
+use crate::setup::asset_registry::AssetRegistry;
+use crate::setup::catalog::{spawn_product, Product, ProductCatalog, ProductPose};
+use crate::setup::config::{srgb, AppConfig, ConfigChanged, ConfigError, FogConfig};
+use crate::setup::orbiting_camera::{AutoOrbit, OrbitCamera};
+use crate::setup::particles::{DustParticles, ParticleBurst};
+
+/// Ordner mit den Szenen-Presets (`<name>.json`, relativ zum Arbeitsverzeichnis)
+pub const PRESETS_DIR: &str = "assets/presets";
+
+/// Nebel-Distanzen für Presets ohne Nebel (Nebel wird weggeblendet statt entfernt)
+const FOG_OFF_START: f32 = 1000.0;
+const FOG_OFF_END: f32 = 2000.0;
+
+/// Einstellungen für den Preset-Wechsel (`presets`)
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct PresetsConfig {
+    /// Preset beim Start (leer = alle Katalogprodukte, Licht aus `rendering`)
+    pub initial: Option<String>,
+    /// Dauer der Überblendung zwischen zwei Presets
+    pub transition_secs: f32,
+}
+
+impl Default for PresetsConfig {
+    fn default() -> Self {
+        Self { initial: None, transition_secs: 2.0 }
+    }
+}
+
+/// Produkt im Preset: nur die ID (Standardpose aus dem Katalog) oder mit eigener Pose
+#[derive(Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum PresetProduct {
+    Id(String),
+    Placed { id: String, pose: ProductPose },
+}
+
+impl PresetProduct {
+    pub fn id(&self) -> &str {
+        match self {
+            PresetProduct::Id(id) => id,
+            PresetProduct::Placed { id, .. } => id,
+        }
+    }
+
+    pub fn pose(&self) -> Option<&ProductPose> {
+        match self {
+            PresetProduct::Id(_) => None,
+            PresetProduct::Placed { pose, .. } => Some(pose),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct PresetLighting {
+    pub ambient_brightness: f32,
+    pub ambient_color: [f32; 3],
+    pub directional_illuminance: f32,
+    pub directional_color: [f32; 3],
+    pub environment_intensity: f32,
+    /// Andere Environment-Map (Pfad im Asset-Ordner); leer = beibehalten
+    pub environment_map: Option<String>,
+    pub clear_color: [f32; 3],
+}
+
+impl Default for PresetLighting {
+    fn default() -> Self {
+        Self {
+            ambient_brightness: 75.0,
+            ambient_color: [1.0, 1.0, 1.0],
+            directional_illuminance: 8000.0,
+            directional_color: [1.0, 1.0, 1.0],
+            environment_intensity: 10000.0,
+            environment_map: None,
+            clear_color: [0.6, 0.6, 0.6],
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct PresetCamera {
+    pub target: [f32; 3],
+    pub radius: f32,
+    pub orbit_speed: f32,
+}
+
+impl Default for PresetCamera {
+    fn default() -> Self {
+        Self { target: [0.0, 0.0, 0.0], radius: 15.0, orbit_speed: 0.01 }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct PresetParticles {
+    /// Schwebender Staub im Hintergrund
+    pub dust: bool,
+    /// Partikel-Burst am Kamera-Ziel, sobald das Preset erscheint
+    pub burst: bool,
+}
+
+impl Default for PresetParticles {
+    fn default() -> Self {
+        Self { dust: true, burst: false }
+    }
+}
+
+/// Ein Szenen-Preset aus `assets/presets/<name>.json`
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct ScenePreset {
+    pub products: Vec<PresetProduct>,
+    pub lighting: PresetLighting,
+    pub fog: FogConfig,
+    pub camera: PresetCamera,
+    pub particles: PresetParticles,
+}
+
+/// Alle geladenen Presets nach Name (Dateiname ohne Endung)
+#[derive(Resource, Default, Debug)]
+pub struct ScenePresets {
+    presets: HashMap<String, ScenePreset>,
+}
+
+impl ScenePresets {
+    /// Liest alle `*.json` aus `dir`; ein fehlender Ordner bedeutet "keine Presets"
+    pub fn load_dir(dir: &str, catalog: &ProductCatalog) -> Result<Self, ConfigError> {
+        let path = Path::new(dir);
+        if !path.is_dir() {
+            return Ok(Self::default());
+        }
+        let entries = fs::read_dir(path).map_err(|source| ConfigError::Io { path: dir.to_string(), source })?;
+
+        let mut presets = HashMap::new();
+        let mut problems = Vec::new();
+        for entry in entries {
+            let file = entry.map_err(|source| ConfigError::Io { path: dir.to_string(), source })?.path();
+            if file.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let Some(name) = file.file_stem().and_then(|stem| stem.to_str()).map(str::to_string) else {
+                continue;
+            };
+            let file_path = file.display().to_string();
+            let contents = fs::read_to_string(&file).map_err(|source| ConfigError::Io {
+                path: file_path.clone(),
+                source,
+            })?;
+            let preset: ScenePreset = serde_json::from_str(&contents).map_err(|source| ConfigError::Parse {
+                path: file_path,
+                source,
+            })?;
+
+            for product in &preset.products {
+                if catalog.get(product.id()).is_none() {
+                    problems.push(format!("Preset '{}': Produkt '{}' fehlt im Katalog", name, product.id()));
+                }
+            }
+            if preset.fog.enabled && preset.fog.start >= preset.fog.end {
+                problems.push(format!("Preset '{}': fog.start muss kleiner als fog.end sein", name));
+            }
+            presets.insert(name, preset);
+        }
+
+        if problems.is_empty() {
+            Ok(Self { presets })
+        } else {
+            Err(ConfigError::Invalid { path: dir.to_string(), problems })
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ScenePreset> {
+        self.presets.get(name)
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.presets.keys().cloned().collect();
+        names.sort();
+        names
+    }
+}
+
+/// Name des aktuell aktiven Presets und der Faktor, mit dem sein Licht skaliert ist
+#[derive(Resource, Debug)]
+pub struct ActivePreset {
+    pub name: Option<String>,
+    pub lighting_scale: f32,
+}
+
+impl Default for ActivePreset {
+    fn default() -> Self {
+        Self { name: None, lighting_scale: 1.0 }
+    }
+}
+
+/// Wechselt mit Überblendung zum angegebenen Preset
+#[derive(Message, Clone, Debug)]
+pub struct SwitchScenePreset {
+    pub name: String,
+    /// Ohne Überblendung (z.B. beim Start)
+    pub instant: bool,
+    /// Skaliert Ambient-, Richtungs- und Environment-Licht (z.B. nachts dunkler); leer = beibehalten
+    pub lighting_scale: Option<f32>,
+}
+
+/// Alle überblendbaren Werte einer Szene
+#[derive(Clone, Copy, Debug)]
+struct SceneLook {
+    ambient_brightness: f32,
+    ambient_color: Vec3,
+    directional_illuminance: f32,
+    directional_color: Vec3,
+    environment_intensity: f32,
+    clear_color: Vec3,
+    fog_color: Vec3,
+    fog_start: f32,
+    fog_end: f32,
+    camera_target: Vec3,
+    camera_radius: f32,
+    orbit_speed: f32,
+}
+
+impl SceneLook {
+    fn from_preset(preset: &ScenePreset) -> Self {
+        let lighting = &preset.lighting;
+        let (fog_start, fog_end) = if preset.fog.enabled {
+            (preset.fog.start, preset.fog.end)
+        } else {
+            (FOG_OFF_START, FOG_OFF_END)
+        };
+        Self {
+            ambient_brightness: lighting.ambient_brightness,
+            ambient_color: Vec3::from_array(lighting.ambient_color),
+            directional_illuminance: lighting.directional_illuminance,
+            directional_color: Vec3::from_array(lighting.directional_color),
+            environment_intensity: lighting.environment_intensity,
+            clear_color: Vec3::from_array(lighting.clear_color),
+            fog_color: Vec3::from_array(preset.fog.color),
+            fog_start,
+            fog_end,
+            camera_target: Vec3::from_array(preset.camera.target),
+            camera_radius: preset.camera.radius,
+            orbit_speed: preset.camera.orbit_speed,
+        }
+    }
+
+    fn lerp(&self, to: &Self, t: f32) -> Self {
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        Self {
+            ambient_brightness: mix(self.ambient_brightness, to.ambient_brightness),
+            ambient_color: self.ambient_color.lerp(to.ambient_color, t),
+            directional_illuminance: mix(self.directional_illuminance, to.directional_illuminance),
+            directional_color: self.directional_color.lerp(to.directional_color, t),
+            environment_intensity: mix(self.environment_intensity, to.environment_intensity),
+            clear_color: self.clear_color.lerp(to.clear_color, t),
+            fog_color: self.fog_color.lerp(to.fog_color, t),
+            fog_start: mix(self.fog_start, to.fog_start),
+            fog_end: mix(self.fog_end, to.fog_end),
+            camera_target: self.camera_target.lerp(to.camera_target, t),
+            camera_radius: mix(self.camera_radius, to.camera_radius),
+            orbit_speed: mix(self.orbit_speed, to.orbit_speed),
+        }
+    }
+
+    fn with_lighting_scale(mut self, scale: f32) -> Self {
+        self.ambient_brightness *= scale;
+        self.directional_illuminance *= scale;
+        self.environment_intensity *= scale;
+        self
+    }
+}
+
+fn color_to_vec3(color: Color) -> Vec3 {
+    let srgba = color.to_srgba();
+    Vec3::new(srgba.red, srgba.green, srgba.blue)
+}
+
+/// Überblendung eines Produkts beim Preset-Wechsel
+#[derive(Component)]
+pub enum PresetFade {
+    /// Schrumpft in der ersten Hälfte und wird zur Hälfte entfernt
+    Out { base_scale: Vec3 },
+    /// Wächst in der zweiten Hälfte auf `base_scale`
+    In { base_scale: Vec3 },
+    /// Bleibt im Preset und gleitet über die ganze Überblendung zur neuen Pose
+    Move { from: Transform, to: Transform },
+}
+
+/// Laufende Überblendung: erste Hälfte blendet aus, zweite Hälfte blendet ein
+#[derive(Resource)]
+pub struct PresetTransition {
+    name: String,
+    lighting_scale: f32,
+    elapsed: f32,
+    duration: f32,
+    from: SceneLook,
+    to: SceneLook,
+    /// Produkte wurden bereits getauscht (Hälfte der Überblendung erreicht)
+    swapped: bool,
+    /// Die Environment-Map wechselt: Intensität geht zur Hälfte über null
+    environment_map: Option<String>,
+}
+
+/// Liste der Produkte, die ein Preset in der Szene haben will
+pub fn preset_product_ids(preset: &ScenePreset) -> HashSet<&str> {
+    preset.products.iter().map(PresetProduct::id).collect()
+}
+
+/// Übernimmt das Start-Preset ohne Überblendung (Produkte spawnt `spawn_initial_objects`)
+pub fn apply_initial_preset(
+    config: Res<AppConfig>,
+    presets: Res<ScenePresets>,
+    mut switch_writer: MessageWriter<SwitchScenePreset>,
+) {
+    let Some(name) = config.presets.initial.as_ref() else {
+        return;
+    };
+    if presets.get(name).is_none() {
+        warn!("Start-Preset '{}' nicht gefunden (verfügbar: {})", name, presets.names().join(", "));
+        return;
+    }
+    switch_writer.write(SwitchScenePreset { name: name.clone(), instant: true, lighting_scale: None });
+}
+
+/// Startet die Überblendung; der aktuelle Zustand der Szene ist der Ausgangspunkt
+pub fn start_preset_transition(
+    mut switch_reader: MessageReader<SwitchScenePreset>,
+    config: Res<AppConfig>,
+    presets: Res<ScenePresets>,
+    active: Res<ActivePreset>,
+    catalog: Res<ProductCatalog>,
+    mut scene: PresetScene,
+    products: Query<(Entity, &Product, &Transform)>,
+) {
+    let Some(request) = switch_reader.read().last() else {
+        return;
+    };
+    let Some(preset) = presets.get(&request.name) else {
+        warn!("Szenen-Preset '{}' unbekannt", request.name);
+        return;
+    };
+    let lighting_scale = request.lighting_scale.unwrap_or(active.lighting_scale).max(0.0);
+    if active.name.as_deref() == Some(request.name.as_str()) && active.lighting_scale == lighting_scale {
+        info!("🎬 Preset '{}' ist bereits aktiv", request.name);
+        return;
+    }
+
+    let to = SceneLook::from_preset(preset).with_lighting_scale(lighting_scale);
+    let from = scene.current_look(to);
+
+    // Produkte, die das neue Preset nicht enthält, schrumpfen in der ersten Hälfte.
+    // Alle anderen gleiten zu ihrer Pose im neuen Preset; das ersetzt auch eine
+    // abgebrochene Überblendung, die sie sonst halb geschrumpft zurücklassen würde.
+    for (entity, product, transform) in &products {
+        let target = preset
+            .products
+            .iter()
+            .find(|preset_product| preset_product.id() == product.id)
+            .and_then(|preset_product| {
+                let entry = catalog.get(&product.id)?;
+                Some(preset_product.pose().unwrap_or(&entry.pose).transform())
+            });
+        let fade = match target {
+            Some(to) => PresetFade::Move { from: *transform, to },
+            None => PresetFade::Out { base_scale: transform.scale },
+        };
+        scene.commands.entity(entity).insert(fade);
+    }
+
+    let duration = if request.instant { 0.0 } else { config.presets.transition_secs.max(0.0) };
+    info!("🎬 Wechsel zu Preset '{}' (Licht ×{:.2}, {:.1}s)", request.name, lighting_scale, duration);
+    scene.commands.insert_resource(PresetTransition {
+        name: request.name.clone(),
+        lighting_scale,
+        elapsed: 0.0,
+        duration,
+        from,
+        to,
+        swapped: false,
+        // Nur das Licht ändert sich: Environment-Map bleibt ohne Abblenden
+        environment_map: preset
+            .lighting
+            .environment_map
+            .clone()
+            .filter(|_| active.name.as_deref() != Some(request.name.as_str())),
+    });
+}
+
+/// Nach einer Änderung der settings.json setzen `apply_lighting_config` und `apply_camera_config`
+/// Licht, Nebel und Environment-Map auf `rendering` zurück – das aktive Preset (samt
+/// `lighting_scale` der Playlist) gilt weiter. Kamera-Ziel und Radius bleiben, wo sie sind.
+pub fn reapply_active_preset(
+    mut changes: MessageReader<ConfigChanged>,
+    presets: Res<ScenePresets>,
+    active: Res<ActivePreset>,
+    transition: Option<Res<PresetTransition>>,
+    mut scene: PresetScene,
+) {
+    if changes.read().last().is_none() {
+        return;
+    }
+    // Eine laufende Überblendung setzt die Werte ohnehin in jedem Frame
+    if transition.is_some() {
+        return;
+    }
+    let Some((name, preset)) = active.name.as_ref().and_then(|name| Some((name, presets.get(name)?))) else {
+        return;
+    };
+
+    let mut look = SceneLook::from_preset(preset).with_lighting_scale(active.lighting_scale);
+    let current = scene.current_look(look);
+    look.camera_target = current.camera_target;
+    look.camera_radius = current.camera_radius;
+    apply_look(&look, &mut scene);
+
+    if let Some(path) = &preset.lighting.environment_map {
+        let map: Handle<Image> = scene.asset_server.load(path.clone());
+        for mut env in &mut scene.environment {
+            env.diffuse_map = map.clone();
+            env.specular_map = map.clone();
+        }
+    }
+    info!("🎬 Preset '{}' nach Konfigurationsänderung wiederhergestellt", name);
+}
+
+/// Alles, was die Überblendung in der Szene verändert
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct PresetScene<'w, 's> {
+    commands: Commands<'w, 's>,
+    asset_server: Res<'w, AssetServer>,
+    ambient: Option<ResMut<'w, AmbientLight>>,
+    clear_color: ResMut<'w, ClearColor>,
+    directional: Query<'w, 's, &'static mut DirectionalLight>,
+    environment: Query<'w, 's, &'static mut EnvironmentMapLight>,
+    cameras: Query<'w, 's, (Entity, &'static mut OrbitCamera, &'static mut AutoOrbit, Option<&'static mut DistanceFog>)>,
+    dust: Query<'w, 's, &'static mut Visibility, With<DustParticles>>,
+    burst_writer: MessageWriter<'w, ParticleBurst>,
+}
+
+impl PresetScene<'_, '_> {
+    /// Liest den aktuellen Zustand der Szene; fehlende Teile übernehmen den Zielwert
+    fn current_look(&self, target: SceneLook) -> SceneLook {
+        let mut look = target;
+        if let Some(ambient) = self.ambient.as_ref() {
+            look.ambient_brightness = ambient.brightness;
+            look.ambient_color = color_to_vec3(ambient.color);
+        }
+        if let Some(light) = self.directional.iter().next() {
+            look.directional_illuminance = light.illuminance;
+            look.directional_color = color_to_vec3(light.color);
+        }
+        if let Some(env) = self.environment.iter().next() {
+            look.environment_intensity = env.intensity;
+        }
+        look.clear_color = color_to_vec3(self.clear_color.0);
+        if let Some((_, orbit, auto_orbit, fog)) = self.cameras.iter().next() {
+            look.camera_target = orbit.target;
+            look.camera_radius = orbit.radius;
+            look.orbit_speed = auto_orbit.speed;
+            match fog {
+                Some(fog) => {
+                    look.fog_color = color_to_vec3(fog.color);
+                    if let FogFalloff::Linear { start, end } = fog.falloff {
+                        look.fog_start = start;
+                        look.fog_end = end;
+                    }
+                }
+                None => {
+                    look.fog_start = FOG_OFF_START;
+                    look.fog_end = FOG_OFF_END;
+                }
+            }
+        }
+        look
+    }
+}
+
+/// Die Asset-Seite des Produkt-Tauschs
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct PresetProducts<'w, 's> {
+    catalog: Res<'w, ProductCatalog>,
+    registry: Option<Res<'w, AssetRegistry>>,
+    gltf_assets: Res<'w, Assets<Gltf>>,
+    gltf_mesh_assets: Res<'w, Assets<bevy::gltf::GltfMesh>>,
+    mesh_assets: Res<'w, Assets<Mesh>>,
+    products: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Product,
+            &'static mut Transform,
+            Option<&'static PresetFade>,
+            Option<&'static mut LinearVelocity>,
+            Option<&'static mut AngularVelocity>,
+        ),
+    >,
+}
+
+/// Blendet Licht, Nebel, Kamera und Produkte zwischen zwei Presets über
+pub fn run_preset_transition(
+    time: Res<Time>,
+    presets: Res<ScenePresets>,
+    transition: Option<ResMut<PresetTransition>>,
+    mut active: ResMut<ActivePreset>,
+    mut scene: PresetScene,
+    mut products: PresetProducts,
+) {
+    let Some(mut transition) = transition else {
+        return;
+    };
+    let Some(preset) = presets.get(&transition.name) else {
+        scene.commands.remove_resource::<PresetTransition>();
+        return;
+    };
+
+    transition.elapsed += time.delta_secs();
+    let t = if transition.duration > 0.0 { (transition.elapsed / transition.duration).min(1.0) } else { 1.0 };
+    let eased = t * t * (3.0 - 2.0 * t);
+    let mut look = transition.from.lerp(&transition.to, eased);
+
+    // Neue Environment-Map: Intensität fällt zur Hälfte auf null und steigt dann wieder
+    if transition.environment_map.is_some() {
+        look.environment_intensity = if t < 0.5 {
+            transition.from.environment_intensity * (1.0 - t * 2.0)
+        } else {
+            transition.to.environment_intensity * (t * 2.0 - 1.0)
+        };
+    }
+
+    if t >= 0.5 && !transition.swapped {
+        transition.swapped = true;
+        swap_products(preset, t >= 1.0, &mut scene, &mut products);
+        if let Some(path) = &transition.environment_map {
+            let map: Handle<Image> = scene.asset_server.load(path.clone());
+            for mut env in &mut scene.environment {
+                env.diffuse_map = map.clone();
+                env.specular_map = map.clone();
+            }
+        }
+        for mut visibility in &mut scene.dust {
+            *visibility = if preset.particles.dust { Visibility::Inherited } else { Visibility::Hidden };
+        }
+        if preset.particles.burst {
+            scene.burst_writer.write(ParticleBurst { position: transition.to.camera_target });
+        }
+    }
+
+    apply_look(&look, &mut scene);
+    fade_products(t, eased, &mut scene.commands, &mut products);
+
+    if t >= 1.0 {
+        active.name = Some(transition.name.clone());
+        active.lighting_scale = transition.lighting_scale;
+        info!("🎬 Preset '{}' aktiv", transition.name);
+        scene.commands.remove_resource::<PresetTransition>();
+    }
+}
+
+fn apply_look(look: &SceneLook, scene: &mut PresetScene) {
+    if let Some(ambient) = scene.ambient.as_mut() {
+        ambient.brightness = look.ambient_brightness;
+        ambient.color = srgb(look.ambient_color.to_array());
+    }
+    scene.clear_color.0 = srgb(look.clear_color.to_array());
+    for mut light in &mut scene.directional {
+        light.illuminance = look.directional_illuminance;
+        light.color = srgb(look.directional_color.to_array());
+    }
+    for mut env in &mut scene.environment {
+        env.intensity = look.environment_intensity;
+    }
+
+    let fog = DistanceFog {
+        color: srgb(look.fog_color.to_array()),
+        falloff: FogFalloff::Linear { start: look.fog_start, end: look.fog_end },
+        ..default()
+    };
+    for (entity, mut orbit, mut auto_orbit, existing_fog) in &mut scene.cameras {
+        orbit.target = look.camera_target;
+        orbit.radius = look.camera_radius.clamp(orbit.min_radius, orbit.max_radius);
+        auto_orbit.speed = look.orbit_speed;
+        match existing_fog {
+            Some(mut existing) => *existing = fog.clone(),
+            None => {
+                scene.commands.entity(entity).insert(fog.clone());
+            }
+        }
+    }
+}
+
+/// Zur Hälfte der Überblendung: ausgeblendete Produkte entfernen, fehlende (klein) spawnen.
+/// Ist die Überblendung schon vorbei (`complete`), erscheinen sie direkt in voller Größe.
+fn swap_products(preset: &ScenePreset, complete: bool, scene: &mut PresetScene, products: &mut PresetProducts) {
+    let wanted = preset_product_ids(preset);
+    let mut present = HashSet::new();
+
+    for (entity, product, _, _, _, _) in &products.products {
+        if wanted.contains(product.id.as_str()) {
+            present.insert(product.id.clone());
+        } else {
+            scene.commands.entity(entity).despawn();
+        }
+    }
+
+    let Some(registry) = products.registry.as_ref() else {
+        return;
+    };
+    for preset_product in &preset.products {
+        if present.contains(preset_product.id()) {
+            continue;
+        }
+        let Some(entry) = products.catalog.get(preset_product.id()) else {
+            continue;
+        };
+        let transform = preset_product.pose().unwrap_or(&entry.pose).transform();
+        let spawn_scale = if complete { transform.scale } else { transform.scale * 0.01 };
+        let Some(entity) = spawn_product(
+            &mut scene.commands,
+            registry,
+            &products.gltf_assets,
+            &products.gltf_mesh_assets,
+            &products.mesh_assets,
+            entry,
+            Some(transform.with_scale(spawn_scale)),
+        ) else {
+            continue;
+        };
+        scene.commands.entity(entity).insert(Selectable);
+        if !complete {
+            scene.commands.entity(entity).insert(PresetFade::In { base_scale: transform.scale });
+        }
+    }
+}
+
+/// Ausblendende Produkte schrumpfen in der ersten Hälfte, neue wachsen in der zweiten,
+/// verbleibende gleiten zu ihrer neuen Pose
+fn fade_products(t: f32, eased: f32, commands: &mut Commands, products: &mut PresetProducts) {
+    for (entity, _, mut transform, fade, linear, angular) in &mut products.products {
+        let Some(fade) = fade else {
+            continue;
+        };
+        match fade {
+            PresetFade::Out { base_scale } => {
+                transform.scale = *base_scale * (1.0 - t * 2.0).clamp(0.01, 1.0);
+            }
+            PresetFade::In { base_scale } => {
+                transform.scale = *base_scale * ((t - 0.5) * 2.0).clamp(0.01, 1.0);
+            }
+            PresetFade::Move { from, to } => {
+                transform.translation = from.translation.lerp(to.translation, eased);
+                transform.rotation = from.rotation.slerp(to.rotation, eased);
+                transform.scale = from.scale.lerp(to.scale, eased);
+                // Die Physik soll nicht gegen die Überblendung arbeiten
+                if let Some(mut linear) = linear {
+                    linear.0 = Vec3::ZERO;
+                }
+                if let Some(mut angular) = angular {
+                    angular.0 = Vec3::ZERO;
+                }
+            }
+        }
+        if t >= 1.0 && !matches!(fade, PresetFade::Out { .. }) {
+            // Die Preset-Pose ist die neue Ausgangsgröße für Pinch-Skalierung
+            commands.entity(entity).remove::<(PresetFade, OriginalScale)>();
+        }
+    }
+}
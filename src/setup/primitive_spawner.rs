@@ -1,4 +1,4 @@
-use avian3d::prelude::{AngularVelocity, Collider, Friction, LinearVelocity, Mass, Restitution, RigidBody};
+use avian3d::prelude::{AngularVelocity, Collider, CollisionEventsEnabled, Friction, LinearVelocity, Mass, Restitution, RigidBody};
 use bevy::asset::Handle;
 use bevy::math::Vec3;
 use bevy::mesh::{Mesh, Mesh3d};
@@ -29,6 +29,7 @@ pub fn spawn_primitive_with_physics(
         Mass(mass),
         Restitution::new(restitution),
         Friction::new(friction),
+        CollisionEventsEnabled,
     ));
     if linear_velocity != Vec3::ZERO {
         entity.insert(LinearVelocity(linear_velocity));
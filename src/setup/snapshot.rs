@@ -0,0 +1,234 @@
+use avian3d::prelude::*;
+use bevy::asset::ron;
+use bevy::prelude::*;
+use bevy::scene::serde::SceneDeserializer;
+use bevy::scene::DynamicEntity;
+use serde::de::DeserializeSeed;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+use crate::gamelogic::Selectable;
+use crate::setup::asset_registry::AssetRegistry;
+use crate::setup::catalog::{spawn_product, CatalogEntry, Product, ProductCatalog};
+use crate::setup::config::AppConfig;
+use crate::setup::presets::PresetTransition;
+use crate::setup::world::RadialGravity;
+
+/// Einstellungen für Szenen-Snapshots (`snapshot`)
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct SnapshotConfig {
+    /// Zieldatei (relativ zum Arbeitsverzeichnis)
+    pub path: String,
+    /// Beim Start die gespeicherte Anordnung laden statt neu zu simulieren
+    pub load_on_start: bool,
+}
+
+impl Default for SnapshotConfig {
+    fn default() -> Self {
+        Self { path: "assets/scenes/snapshot.scn.ron".to_string(), load_on_start: false }
+    }
+}
+
+/// Speichert bzw. lädt die Anordnung aller Produkte (Taste F6 / F7 oder Fernsteuerung)
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SceneSnapshot {
+    Save,
+    Load,
+}
+
+/// Lädt den Snapshot beim Start, falls `snapshot.load_on_start` gesetzt ist
+pub fn load_snapshot_on_start(config: Res<AppConfig>, mut snapshot_writer: MessageWriter<SceneSnapshot>) {
+    if config.snapshot.load_on_start {
+        snapshot_writer.write(SceneSnapshot::Load);
+    }
+}
+
+/// Snapshot speichern mit F6, laden mit F7
+pub fn snapshot_input(keyboard: Res<ButtonInput<KeyCode>>, mut snapshot_writer: MessageWriter<SceneSnapshot>) {
+    if keyboard.just_pressed(KeyCode::F6) {
+        snapshot_writer.write(SceneSnapshot::Save);
+    }
+    if keyboard.just_pressed(KeyCode::F7) {
+        snapshot_writer.write(SceneSnapshot::Load);
+    }
+}
+
+/// Schreibt Transform, Geschwindigkeiten, Gravitations-Marker und Produkt-ID aller Produkte als DynamicScene
+pub fn save_scene_snapshot(
+    world: &World,
+    mut requests: MessageReader<SceneSnapshot>,
+    products: Query<Entity, (With<Product>, With<RigidBody>)>,
+) {
+    if !requests.read().any(|request| *request == SceneSnapshot::Save) {
+        return;
+    }
+    let Some(config) = world.get_resource::<AppConfig>() else {
+        return;
+    };
+    let path = &config.snapshot.path;
+
+    let scene = DynamicSceneBuilder::from_world(world)
+        .deny_all()
+        .allow_component::<Transform>()
+        .allow_component::<LinearVelocity>()
+        .allow_component::<AngularVelocity>()
+        .allow_component::<RadialGravity>()
+        .allow_component::<Product>()
+        .extract_entities(products.iter())
+        .build();
+
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let serialized = match scene.serialize(&type_registry) {
+        Ok(serialized) => serialized,
+        Err(e) => {
+            warn!("⚠️ Snapshot konnte nicht serialisiert werden: {}", e);
+            return;
+        }
+    };
+    if let Some(parent) = Path::new(path).parent() {
+        if let Err(e) = fs::create_dir_all(parent) {
+            warn!("⚠️ Ordner für Snapshot '{}' konnte nicht angelegt werden: {}", path, e);
+            return;
+        }
+    }
+    match fs::write(path, serialized) {
+        Ok(()) => info!("💾 Snapshot mit {} Produkten gespeichert: {}", scene.entities.len(), path),
+        Err(e) => warn!("⚠️ Snapshot '{}' konnte nicht geschrieben werden: {}", path, e),
+    }
+}
+
+/// Was zum Spawnen der Produkte aus einem Snapshot gebraucht wird
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct SnapshotSpawner<'w> {
+    catalog: Res<'w, ProductCatalog>,
+    registry: Option<Res<'w, AssetRegistry>>,
+    gltf_assets: Res<'w, Assets<Gltf>>,
+    gltf_mesh_assets: Res<'w, Assets<bevy::gltf::GltfMesh>>,
+    mesh_assets: Res<'w, Assets<Mesh>>,
+}
+
+impl SnapshotSpawner<'_> {
+    /// Katalogeintrag zum Snapshot-Produkt, sofern sein Modell fertig geladen ist
+    fn resolve(&self, registry: &AssetRegistry, product: &Product) -> Option<&CatalogEntry> {
+        let Some(entry) = self.catalog.get(&product.id) else {
+            warn!("Snapshot: Produkt '{}' fehlt im Katalog", product.id);
+            return None;
+        };
+        let loaded = registry
+            .model(&entry.model)
+            .is_ok_and(|model| self.gltf_assets.get(&model.visual).is_some_and(|gltf| !gltf.scenes.is_empty()));
+        if !loaded {
+            warn!("Snapshot: Modell '{}' für '{}' ist nicht geladen", entry.model, product.id);
+            return None;
+        }
+        Some(entry)
+    }
+}
+
+/// Ersetzt alle Produkte durch die Anordnung aus dem Snapshot.
+/// Die bisherigen Produkte bleiben stehen, wenn kein Eintrag des Snapshots gespawnt werden kann.
+pub fn load_scene_snapshot(
+    mut commands: Commands,
+    mut requests: MessageReader<SceneSnapshot>,
+    config: Res<AppConfig>,
+    type_registry: Res<AppTypeRegistry>,
+    spawner: SnapshotSpawner,
+    products: Query<Entity, With<Product>>,
+    transition: Option<Res<PresetTransition>>,
+) {
+    if !requests.read().any(|request| *request == SceneSnapshot::Load) {
+        return;
+    }
+    // Der Produkttausch in der Mitte der Überblendung würde den Snapshot sofort überschreiben
+    if transition.is_some() {
+        warn!("Snapshot wird während eines Preset-Wechsels nicht geladen");
+        return;
+    }
+    let path = &config.snapshot.path;
+    let Some(registry) = spawner.registry.as_ref() else {
+        warn!("Snapshot kann erst nach dem Laden der Assets übernommen werden");
+        return;
+    };
+    let scene = match read_snapshot(path, &type_registry) {
+        Ok(scene) => scene,
+        Err(e) => {
+            warn!("⚠️ Snapshot '{}' konnte nicht geladen werden: {}", path, e);
+            return;
+        }
+    };
+
+    let resolved: Vec<(&DynamicEntity, &CatalogEntry)> = scene
+        .entities
+        .iter()
+        .filter_map(|snapshot_entity| {
+            let product = snapshot_component::<Product>(snapshot_entity)?;
+            Some((snapshot_entity, spawner.resolve(registry, &product)?))
+        })
+        .collect();
+    if resolved.is_empty() {
+        warn!("⚠️ Snapshot '{}' enthält kein spawnbares Produkt, Anordnung bleibt unverändert", path);
+        return;
+    }
+
+    for entity in &products {
+        commands.entity(entity).despawn();
+    }
+
+    let mut spawned = 0;
+    for (snapshot_entity, entry) in resolved {
+        let transform = snapshot_component::<Transform>(snapshot_entity).unwrap_or_else(|| entry.pose.transform());
+        let Some(entity) = spawn_product(
+            &mut commands,
+            registry,
+            &spawner.gltf_assets,
+            &spawner.gltf_mesh_assets,
+            &spawner.mesh_assets,
+            entry,
+            Some(transform),
+        ) else {
+            continue;
+        };
+
+        let mut entity = commands.entity(entity);
+        entity.insert((
+            Selectable,
+            snapshot_component::<LinearVelocity>(snapshot_entity).unwrap_or_default(),
+            snapshot_component::<AngularVelocity>(snapshot_entity).unwrap_or_default(),
+        ));
+        // Der Marker im Snapshot gilt, nicht die Voreinstellung aus dem Katalog
+        if find_component::<RadialGravity>(snapshot_entity).is_some() {
+            entity.insert(RadialGravity);
+        } else {
+            entity.remove::<RadialGravity>();
+        }
+        spawned += 1;
+    }
+    info!("📂 Snapshot geladen: {} Produkte aus {}", spawned, path);
+}
+
+fn read_snapshot(path: &str, type_registry: &AppTypeRegistry) -> Result<DynamicScene, String> {
+    let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let mut deserializer = ron::de::Deserializer::from_str(&contents).map_err(|e| e.to_string())?;
+    let type_registry = type_registry.read();
+    SceneDeserializer { type_registry: &type_registry }
+        .deserialize(&mut deserializer)
+        .map_err(|e| e.to_string())
+}
+
+fn find_component<T: TypePath>(entity: &DynamicEntity) -> Option<&dyn PartialReflect> {
+    entity
+        .components
+        .iter()
+        .find(|component| {
+            component
+                .get_represented_type_info()
+                .is_some_and(|info| info.type_path() == T::type_path())
+        })
+        .map(|component| component.as_ref())
+}
+
+fn snapshot_component<T: FromReflect + TypePath>(entity: &DynamicEntity) -> Option<T> {
+    find_component::<T>(entity).and_then(T::from_reflect)
+}
@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use crate::setup::assetloader::LoadedModels;
+use crate::setup::asset_registry::AssetRegistry;
 use crate::setup::gltf_spawner::{GltfSpawnConfig, spawn_gltf_with_physics};
 use crate::setup::world::RadialGravity;
 use rand::Rng;
@@ -30,21 +30,32 @@ impl Default for StressTestConfig {
 
 pub fn spawn_stress_test_objects(
     mut commands: Commands,
-    loaded_models: Option<Res<LoadedModels>>,
+    registry: Option<Res<AssetRegistry>>,
     gltf_assets: Res<Assets<Gltf>>,
     gltf_mesh_assets: Res<Assets<bevy::gltf::GltfMesh>>,
     mesh_assets: Res<Assets<Mesh>>,
     time: Res<Time>,
     mut config: ResMut<StressTestConfig>,
+    mut reported_missing: Local<bool>,
 ) {
     if !config.enabled || config.current_count >= config.max_objects {
         return;
     }
 
-    // Früher Return wenn LoadedModels fehlt
-    let Some(loaded_models) = loaded_models else {
+    // Früher Return wenn die Registry oder das Modell fehlt
+    let Some(registry) = registry else {
         return;
     };
+    let plant = match registry.model("plant") {
+        Ok(model) => model,
+        Err(e) => {
+            if !*reported_missing {
+                warn!("Stresstest kann nicht spawnen: {}", e);
+                *reported_missing = true;
+            }
+            return;
+        }
+    };
 
     config.spawn_timer.tick(time.delta());
 
@@ -83,29 +94,26 @@ pub fn spawn_stress_test_objects(
 
             let scale = rng.random_range(0.2..8.0);
 
-
-            if let Some(plant_handle) = &loaded_models.plant {
-                let spawn_config = GltfSpawnConfig::new(plant_handle.clone())
-                    .with_collider_gltf(loaded_models.plant_collider.clone().unwrap_or(plant_handle.clone()))
-                    .with_transform(Transform::from_xyz(x, y, z))
-                    .with_scale(scale)
-                    .with_mass(0.2)
-                    .with_physics(0.0, 0.0)
-                    .with_velocity(linear_vel, angular_vel)
-                    .with_radial_gravity(true);
-
-                if let Some(entity) = spawn_gltf_with_physics(
-                    &mut commands,
-                    &gltf_assets,
-                    &gltf_mesh_assets,
-                    &mesh_assets,
-                    spawn_config,
-                    scale,
-                    Some(RadialGravity),
-                ) {
-                    // Füge noch den StressTestObject Marker hinzu
-                    commands.entity(entity).insert(StressTestObject);
-                }
+            let spawn_config = GltfSpawnConfig::new(plant.visual.clone())
+                .with_collider_gltf(plant.collider_or_visual())
+                .with_transform(Transform::from_xyz(x, y, z))
+                .with_scale(scale)
+                .with_mass(0.2)
+                .with_physics(0.0, 0.0)
+                .with_velocity(linear_vel, angular_vel)
+                .with_radial_gravity(true);
+
+            if let Some(entity) = spawn_gltf_with_physics(
+                &mut commands,
+                &gltf_assets,
+                &gltf_mesh_assets,
+                &mesh_assets,
+                spawn_config,
+                scale,
+                Some(RadialGravity),
+            ) {
+                // Füge noch den StressTestObject Marker hinzu
+                commands.entity(entity).insert(StressTestObject);
             }
 
             config.current_count += 1;
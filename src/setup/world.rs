@@ -1,20 +1,23 @@
 use bevy::prelude::*;
 use avian3d::prelude::*;
-use crate::setup::assetloader::{LoadedModels, AmbienceAudio};
+use crate::setup::asset_registry::AssetRegistry;
+use crate::setup::catalog::{spawn_product, CatalogEntry, ProductCatalog, ProductPose};
+use crate::setup::presets::ScenePresets;
 use crate::setup::primitive_spawner::{spawn_primitive_with_physics};
+use crate::setup::config::{AppConfig, ConfigChanged};
+use crate::gamelogic::Selectable;
 
-#[derive(Component)]
-pub struct AmbienceAudioMarker;
-
-#[derive(Component)]
+#[derive(Component, Reflect, Default)]
+#[reflect(Component, Default)]
 pub struct RadialGravity;
 
 pub fn apply_radial_gravity(
     mut query: Query<(&Transform, &mut LinearVelocity), With<RadialGravity>>,
     time: Res<Time>,
+    config: Res<AppConfig>,
 ) {
-    let center = Vec3::ZERO;
-    let strength = 0.5;
+    let center = Vec3::from_array(config.physics.radial_gravity_center);
+    let strength = config.physics.radial_gravity_strength;
 
     for (transform, mut velocity) in query.iter_mut() {
         let position = transform.translation;
@@ -28,71 +31,83 @@ pub fn apply_radial_gravity(
     }
 }
 
+/// Übernimmt die globale Gravitation nach einer Änderung der settings.json
+/// (die radiale Gravitation liest ihre Werte ohnehin jeden Frame aus `AppConfig`)
+pub fn apply_physics_config(
+    mut changes: MessageReader<ConfigChanged>,
+    config: Res<AppConfig>,
+    mut gravity: ResMut<Gravity>,
+) {
+    if changes.read().last().is_some() {
+        gravity.0 = Vec3::from_array(config.physics.gravity);
+    }
+}
+
 pub fn spawn_initial_objects(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
-    loaded_models: Option<Res<LoadedModels>>, // optional machen, um Panics zu vermeiden
-    _gltf_assets: Res<Assets<Gltf>>,
-    _gltf_mesh_assets: Res<Assets<bevy::gltf::GltfMesh>>,
+    registry: Option<Res<AssetRegistry>>, // optional machen, um Panics zu vermeiden
+    catalog: Res<ProductCatalog>,
+    presets: Res<ScenePresets>,
+    config: Res<AppConfig>,
+    gltf_assets: Res<Assets<Gltf>>,
+    gltf_mesh_assets: Res<Assets<bevy::gltf::GltfMesh>>,
 ) {
     info!("🎲 Spawning initial objects!");
 
-    let Some(_loaded_models) = loaded_models else {
-        info!("ℹ️ LoadedModels resource missing; skipping initial object spawn.");
+    let Some(registry) = registry else {
+        info!("ℹ️ AssetRegistry resource missing; skipping initial object spawn.");
         return;
     };
 
-    spawn_primitive_with_physics(
-        &mut commands,
-        meshes.add(Cuboid::from_length(3.0)),
-        materials.add(Color::srgb_u8(240, 240, 240)),
-        Transform::from_xyz(0.0, 4.0, 0.0),
-        Collider::cuboid(3.0, 3.0, 3.0),
-        100.0,
-        0.0,
-        0.0,
-        Vec3::ZERO,
-        Vec3::new(0.1, 0.1, 0.1),
-        1.0,
-        Some(RadialGravity),
-    );
-
-    info!("✅ All initial objects spawned!");
-}
-
-
-
-
+    // Mit Start-Preset nur dessen Produkte, sonst der ganze Katalog
+    let initial_preset = config.presets.initial.as_ref().and_then(|name| presets.get(name));
+    let placements: Vec<(&CatalogEntry, Option<Transform>)> = match initial_preset {
+        Some(preset) => preset
+            .products
+            .iter()
+            .filter_map(|product| {
+                catalog.get(product.id()).map(|entry| (entry, product.pose().map(ProductPose::transform)))
+            })
+            .collect(),
+        None => catalog.products.iter().map(|entry| (entry, None)).collect(),
+    };
 
-pub fn spawn_ambience_when_ready(
-    mut commands: Commands,
-    ambience: Option<Res<AmbienceAudio>>,
-    asset_server: Res<AssetServer>,
-    existing: Query<(), With<AmbienceAudioMarker>>,
-) {
-    if !existing.is_empty() {
-        return;
+    let mut spawned = 0;
+    for (product, transform) in placements {
+        if let Some(entity) = spawn_product(
+            &mut commands,
+            &registry,
+            &gltf_assets,
+            &gltf_mesh_assets,
+            &meshes,
+            product,
+            transform,
+        ) {
+            commands.entity(entity).insert(Selectable);
+            spawned += 1;
+        }
     }
 
-    if let Some(amb) = ambience {
-        if let Some(handle) = &amb.0 {
-            // Prüfen, ob das Audio-Asset vollständig geladen ist
-            if !matches!(
-                asset_server.get_load_state(handle.id()),
-                Some(bevy::asset::LoadState::Loaded)
-            ) {
-                return; // Asset noch nicht geladen, warten
-            }
-
-            commands.spawn((
-                bevy::audio::AudioPlayer::new(handle.clone()),
-                bevy::audio::PlaybackSettings::LOOP
-                    .with_volume(bevy::audio::Volume::Linear(0.5))
-                    .with_spatial(false), // Nicht-räumlich für Hintergrundmusik
-                AmbienceAudioMarker,
-            ));
-            info!("🔊 Ambience audio spawned and playing!");
-        }
+    // Ohne Katalogprodukte bleibt der Platzhalter-Würfel
+    if spawned == 0 {
+        let cube = spawn_primitive_with_physics(
+            &mut commands,
+            meshes.add(Cuboid::from_length(3.0)),
+            materials.add(Color::srgb_u8(240, 240, 240)),
+            Transform::from_xyz(0.0, 4.0, 0.0),
+            Collider::cuboid(3.0, 3.0, 3.0),
+            100.0,
+            0.0,
+            0.0,
+            Vec3::ZERO,
+            Vec3::new(0.1, 0.1, 0.1),
+            1.0,
+            Some(RadialGravity),
+        );
+        commands.entity(cube).insert(Selectable);
     }
-}
\ No newline at end of file
+
+    info!("✅ All initial objects spawned ({} Produkte)!", spawned);
+}